@@ -0,0 +1,61 @@
+use std::io::ErrorKind;
+
+/// Accepted answers for a day, stored in `answers/YYYY/NN` as `part: answer` lines.
+#[derive(Default)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn get(&self, part: usize) -> Option<&str> {
+        self.parts[part - 1].as_deref()
+    }
+
+    pub fn set(&mut self, part: usize, answer: &str) {
+        self.parts[part - 1] = Some(answer.to_owned());
+    }
+}
+
+pub fn load(year: usize, day: usize) -> Result<Answers, std::io::Error> {
+    let contents = match std::fs::read_to_string(path(year, day)) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Answers::default()),
+        Err(e) => return Err(e),
+    };
+
+    let mut answers = Answers::default();
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let (part, answer) = line
+            .split_once(':')
+            .ok_or_else(|| invalid_line(year, day, line))?;
+        match part.trim() {
+            "1" => answers.parts[0] = Some(answer.trim().to_owned()),
+            "2" => answers.parts[1] = Some(answer.trim().to_owned()),
+            _ => return Err(invalid_line(year, day, line)),
+        }
+    }
+
+    Ok(answers)
+}
+
+pub fn save(year: usize, day: usize, answers: &Answers) -> Result<(), std::io::Error> {
+    let contents: String = answers
+        .parts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, answer)| Some(format!("{}: {}\n", i + 1, answer.as_ref()?)))
+        .collect();
+    std::fs::create_dir_all(format!("answers/{year}"))?;
+    std::fs::write(path(year, day), contents)
+}
+
+fn path(year: usize, day: usize) -> String {
+    format!("answers/{year}/{:02}", day)
+}
+
+fn invalid_line(year: usize, day: usize, line: &str) -> std::io::Error {
+    std::io::Error::new(
+        ErrorKind::InvalidData,
+        format!("{}: invalid line \"{line}\"", path(year, day)),
+    )
+}
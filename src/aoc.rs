@@ -0,0 +1,456 @@
+use regex::Regex;
+use reqwest::StatusCode;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (Advent of Code solutions runner)"
+);
+
+/// Client for the Advent of Code site, authenticated with the session token.
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+    /// Tries for each GET request, retrying server errors and timeouts
+    attempts: usize,
+    /// Delay before the first retry, doubled for each next one
+    backoff: Duration,
+    /// Directory where inputs are cached, as they never change
+    cache: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The session token is missing, invalid or expired
+    Session,
+    /// Usually a puzzle that isn't unlocked yet
+    NotFound(String),
+    /// Still failing after retrying
+    Server(StatusCode),
+    Status(StatusCode),
+    Request(reqwest::Error),
+    Cache(std::io::Error),
+}
+
+impl Error {
+    fn is_transient(&self) -> bool {
+        match self {
+            Error::Server(_) => true,
+            Error::Request(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Session => write!(f, "the session token in TOKEN is invalid or expired"),
+            Error::NotFound(url) => write!(f, "{url} not found, is the puzzle unlocked?"),
+            Error::Server(status) => write!(
+                f,
+                "server error {status}, which a malformed session token also causes"
+            ),
+            Error::Status(status) => write!(f, "unexpected response {status}"),
+            Error::Request(e) => write!(f, "request failed: {e}"),
+            Error::Cache(e) => write!(f, "can't use the request cache: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Request(e)
+    }
+}
+
+/// What gets extracted from a day's puzzle page.
+#[derive(Debug, Default, PartialEq)]
+pub struct Puzzle {
+    /// Contents of every `<pre><code>` block, in page order
+    pub examples: Vec<String>,
+    /// Accepted answers, shown on the page once a part is solved
+    pub answers: Vec<String>,
+}
+
+/// The site's response to a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too recently, with the seconds left to wait
+    Wait(u64),
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+    /// A response that isn't recognized, with its text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Short name used in the attempts log.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait(_) => "wait",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(seconds) => write!(f, "submitted too recently, wait {seconds}s"),
+            Verdict::WrongLevel => write!(f, "not the current part, already solved or locked"),
+            Verdict::Unknown(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+impl Client {
+    /// Reads the session token from `TOKEN`, the site from `AOC_URL` (defaulting
+    /// to adventofcode.com) and the contact put in the User-Agent from
+    /// `AOC_CONTACT`. Inputs are cached in `.cache`.
+    pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
+        let token = std::env::var("TOKEN").map_err(|_| "missing TOKEN env variable")?;
+        let base_url = std::env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_owned());
+        let contact = std::env::var("AOC_CONTACT").ok();
+        Ok(Client::new(&base_url, &token, contact.as_deref())?.with_cache(".cache".into()))
+    }
+
+    pub fn new(
+        base_url: &str,
+        token: &str,
+        contact: Option<&str>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        let cookie = format!("session={token}");
+        headers.insert("cookie", reqwest::header::HeaderValue::from_str(&cookie)?);
+        let user_agent = match contact {
+            Some(contact) => format!("{USER_AGENT} by {contact}"),
+            None => USER_AGENT.to_owned(),
+        };
+
+        Ok(Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            http: reqwest::Client::builder()
+                .default_headers(headers)
+                .user_agent(user_agent)
+                .connect_timeout(Duration::from_secs(10))
+                .timeout(Duration::from_secs(30))
+                .build()?,
+            attempts: 3,
+            backoff: Duration::from_secs(1),
+            cache: None,
+        })
+    }
+
+    pub fn with_cache(self, cache: PathBuf) -> Self {
+        Client {
+            cache: Some(cache),
+            ..self
+        }
+    }
+
+    pub async fn input(&self, year: usize, day: usize) -> Result<String, Error> {
+        let path = format!("/{year}/day/{day}/input");
+        let cached = self.cache.as_ref().map(|cache| cache.join(&path[1..]));
+        if let Some(input) = cached
+            .as_ref()
+            .and_then(|c| std::fs::read_to_string(c).ok())
+        {
+            return Ok(input);
+        }
+
+        let input = self.get(&path).await?;
+        if let Some(cached) = cached {
+            std::fs::create_dir_all(cached.parent().unwrap()).map_err(Error::Cache)?;
+            std::fs::write(cached, &input).map_err(Error::Cache)?;
+        }
+        Ok(input)
+    }
+
+    pub async fn puzzle(&self, year: usize, day: usize) -> Result<Puzzle, Error> {
+        let html = self.get(&format!("/{year}/day/{day}")).await?;
+        // Puzzle pages are public, but without a valid session they offer to log in
+        if html.contains("/auth/login") {
+            return Err(Error::Session);
+        }
+        Ok(parse_puzzle(&html))
+    }
+
+    /// Not retried, so an answer is never submitted twice.
+    pub async fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        answer: &str,
+    ) -> Result<Verdict, Error> {
+        let response = self
+            .http
+            .post(format!("{}/{year}/day/{day}/answer", self.base_url))
+            .form(&[("level", part.to_string().as_str()), ("answer", answer)])
+            .send()
+            .await?;
+        Ok(parse_verdict(&check(response).await?))
+    }
+
+    async fn get(&self, path: &str) -> Result<String, Error> {
+        let url = format!("{}{path}", self.base_url);
+        let mut delay = self.backoff;
+        for attempt in 1.. {
+            let result = match self.http.get(&url).send().await {
+                Ok(response) => check(response).await,
+                Err(e) => Err(Error::Request(e)),
+            };
+            match result {
+                Err(e) if e.is_transient() && attempt < self.attempts => {
+                    eprintln!("{e}, retrying in {}s", delay.as_secs_f64());
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                result => return result,
+            }
+        }
+        unreachable!()
+    }
+}
+
+/// The body of a successful response, or the error its status means.
+async fn check(response: reqwest::Response) -> Result<String, Error> {
+    let status = response.status();
+    let url = response.url().to_string();
+    let body = response.text().await?;
+    match status.as_u16() {
+        200..=299 => Ok(body),
+        // The site answers 400 when the session is missing or expired
+        400 | 401 | 403 => Err(Error::Session),
+        404 => Err(Error::NotFound(url)),
+        500..=599 => Err(Error::Server(status)),
+        _ => Err(Error::Status(status)),
+    }
+}
+
+pub fn parse_puzzle(html: &str) -> Puzzle {
+    let examples = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answers = Regex::new(r"Your puzzle answer was <code>(.*?)</code>").unwrap();
+
+    Puzzle {
+        examples: examples
+            .captures_iter(html)
+            .map(|c| unescape(&c[1]))
+            .collect(),
+        answers: answers
+            .captures_iter(html)
+            .map(|c| unescape(&c[1]))
+            .collect(),
+    }
+}
+
+pub fn parse_verdict(html: &str) -> Verdict {
+    let article = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    let text = article
+        .captures(html)
+        .map_or_else(|| unescape(html), |c| unescape(&c[1]));
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        Verdict::Wait(wait.captures(&text).map_or(60, |c| {
+            let minutes = c.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            minutes * 60 + c[2].parse().unwrap_or(0)
+        }))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text.trim().to_owned())
+    }
+}
+
+/// Strips inline markup such as `<em>` and decodes the entities the site uses.
+fn unescape(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    const PAGE: &str = include_str!("../fixtures/puzzle.html");
+
+    /// Serves the responses in order on a local port, repeating the last one.
+    /// Returns the URL and the number of requests served so far.
+    async fn serve(responses: &'static [(u16, &'static str)]) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let served = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut stream = BufReader::new(stream);
+                let mut line = String::new();
+                while stream.read_line(&mut line).await.unwrap() > 2 {
+                    line.clear();
+                }
+                let i = served.fetch_add(1, Ordering::SeqCst);
+                let (status, body) = responses[i.min(responses.len() - 1)];
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn client(url: &str) -> Client {
+        Client {
+            backoff: Duration::from_millis(1),
+            ..Client::new(url, "token", None).unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn downloads_examples_and_answers() {
+        let (url, _) = serve(&[(200, PAGE)]).await;
+        let puzzle = client(&url).puzzle(2023, 1).await.unwrap();
+
+        assert_eq!(
+            puzzle.examples,
+            [
+                "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+                "two1nine\neightwothree\n"
+            ]
+        );
+        assert_eq!(puzzle.answers, ["54601"]);
+    }
+
+    #[tokio::test]
+    async fn submits_answers() {
+        const PAGE: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.</p></article></main>";
+        let (url, _) = serve(&[(200, PAGE)]).await;
+        assert_eq!(
+            client(&url).submit(2023, 1, 1, "142").await.unwrap(),
+            Verdict::Correct
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors() {
+        let (url, requests) = serve(&[(500, ""), (502, ""), (200, "1\n")]).await;
+        assert_eq!(client(&url).input(2023, 1).await.unwrap(), "1\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        let (url, requests) = serve(&[(500, "")]).await;
+        let result = client(&url).input(2023, 1).await;
+        assert!(matches!(result, Err(Error::Server(_))));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn reports_client_errors_without_retrying() {
+        const LOGIN: &str =
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+        let (url, requests) = serve(&[(400, LOGIN)]).await;
+        let result = client(&url).input(2023, 1).await;
+        assert!(matches!(result, Err(Error::Session)));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        const LOCKED: &str = "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.";
+        let (url, _) = serve(&[(404, LOCKED)]).await;
+        let result = client(&url).input(2023, 1).await;
+        assert!(matches!(result, Err(Error::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn caches_inputs() {
+        let cache = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let (url, requests) = serve(&[(200, "1\n")]).await;
+        let client = client(&url).with_cache(cache.clone());
+
+        assert_eq!(client.input(2023, 1).await.unwrap(), "1\n");
+        assert_eq!(client.input(2023, 1).await.unwrap(), "1\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            std::fs::read_to_string(cache.join("2023/day/1/input")).unwrap(),
+            "1\n"
+        );
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let verdict =
+            |text: &str| parse_verdict(&format!("<main><article><p>{text}</p></article></main>"));
+
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 26s left to wait. <a href=\"/2023/day/10\">[Return to Day 10]</a>"),
+            Verdict::Wait(266)
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently.  You have 36s left to wait."),
+            Verdict::Wait(36)
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/10\">[Return to Day 10]</a>"),
+            Verdict::WrongLevel
+        );
+    }
+
+    #[test]
+    fn unsolved_page_has_no_answers() {
+        let puzzle = parse_puzzle("<pre><code>a &lt;b&gt; &amp;&amp; c\n</code></pre>");
+        assert_eq!(puzzle.examples, ["a <b> && c\n"]);
+        assert!(puzzle.answers.is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::aoc::Verdict;
+use std::io::{ErrorKind, Write};
+
+/// A submitted answer, logged in `attempts/YYYY/NN` as `time part verdict answer` lines.
+/// The time is `-` for attempts made before the log existed.
+#[derive(Debug, PartialEq)]
+pub struct Attempt {
+    pub time: String,
+    pub part: usize,
+    pub verdict: String,
+    pub answer: String,
+}
+
+pub fn load(year: usize, day: usize) -> Result<Vec<Attempt>, std::io::Error> {
+    let contents = match std::fs::read_to_string(path(year, day)) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.splitn(4, ' ');
+            let mut next = || fields.next().ok_or_else(|| invalid_line(year, day, line));
+            let (time, part, verdict, answer) = (next()?, next()?, next()?, next()?);
+            Ok(Attempt {
+                time: time.to_owned(),
+                part: part.parse().map_err(|_| invalid_line(year, day, line))?,
+                verdict: verdict.to_owned(),
+                answer: answer.to_owned(),
+            })
+        })
+        .collect()
+}
+
+pub fn record(
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+    verdict: &Verdict,
+) -> std::io::Result<()> {
+    std::fs::create_dir_all(format!("attempts/{year}"))?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path(year, day))?;
+    writeln!(
+        file,
+        "{} {} {} {}",
+        chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+        part,
+        verdict.kind(),
+        answer
+    )
+}
+
+/// Why `answer` is known to be wrong from the previous attempts, if it is.
+/// Numeric answers are also checked against the too high and too low bounds.
+pub fn refusal(attempts: &[Attempt], part: usize, answer: &str) -> Option<String> {
+    let attempts = attempts.iter().filter(|attempt| attempt.part == part);
+    let value = answer.parse::<i128>().ok();
+
+    for attempt in attempts {
+        let bound = attempt.answer.parse::<i128>().ok();
+        match (attempt.verdict.as_str(), value, bound) {
+            ("wrong" | "too-high" | "too-low", _, _) if attempt.answer == answer => {
+                return Some(format!(
+                    "{answer} was already submitted and was {}",
+                    attempt.verdict
+                ));
+            }
+            ("too-high", Some(value), Some(bound)) if value >= bound => {
+                return Some(format!("{answer} is too high, {bound} already was"));
+            }
+            ("too-low", Some(value), Some(bound)) if value <= bound => {
+                return Some(format!("{answer} is too low, {bound} already was"));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn path(year: usize, day: usize) -> String {
+    format!("attempts/{year}/{:02}", day)
+}
+
+fn invalid_line(year: usize, day: usize, line: &str) -> std::io::Error {
+    std::io::Error::new(
+        ErrorKind::InvalidData,
+        format!("{}: invalid line \"{line}\"", path(year, day)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(verdict: &str, answer: &str) -> Attempt {
+        Attempt {
+            time: "2023-12-10T06:00:00Z".to_owned(),
+            part: 2,
+            verdict: verdict.to_owned(),
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let attempts = [
+            attempt("too-high", "580"),
+            attempt("wrong", "63"),
+            attempt("too-low", "40"),
+        ];
+
+        assert!(refusal(&attempts, 2, "63").is_some());
+        assert!(refusal(&attempts, 2, "580").is_some());
+        assert!(refusal(&attempts, 2, "600").is_some());
+        assert!(refusal(&attempts, 2, "12").is_some());
+        assert_eq!(refusal(&attempts, 2, "93"), None);
+        assert_eq!(refusal(&attempts, 1, "63"), None);
+    }
+}
@@ -1,107 +1,570 @@
-use chrono::Datelike;
-use clap::{Args, Parser};
-use std::io::Read;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
 
+mod answers;
+mod aoc;
+mod attempts;
+mod registry;
+mod schedule;
 mod solutions;
 extern crate lazy_static;
 
+/// Year used when neither `--year` nor `AOC_YEAR` is given.
+const DEFAULT_YEAR: usize = 2023;
+
 #[derive(Parser, Debug)]
-enum CLIArgs {
+struct CLIArgs {
+    /// Puzzle year, defaults to `AOC_YEAR` or 2023
+    #[arg(long, global = true)]
+    year: Option<usize>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
     Run(RunCommand),
     Download(DownloadCommand),
+    Verify(VerifyCommand),
+    List,
+    Bench(BenchCommand),
+    Submit(SubmitCommand),
+    New(NewCommand),
 }
 
 #[derive(Args, Debug)]
 struct RunCommand {
-    #[arg(default_value_t = 3)]
-    part: usize,
-    day: Option<usize>,
+    /// A single day (`5`) or a range of days (`3..=9`, `3..10`)
+    #[arg(value_parser = parse_days)]
+    day: Option<RangeInclusive<usize>>,
+    /// Run every implemented day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Read the input from this file instead of `inputs/NN`
+    #[arg(long, conflicts_with_all = ["all", "stdin", "variant", "example"])]
+    input: Option<PathBuf>,
+    /// Read the input from stdin instead of `inputs/NN`
+    #[arg(long, conflicts_with_all = ["all", "variant", "example"])]
+    stdin: bool,
+    /// Read the input from `inputs/NN.<VARIANT>`, e.g. `example1`
+    #[arg(long, conflicts_with = "example")]
+    variant: Option<String>,
+    /// Read the input from the downloaded example `examples/NN-<EXAMPLE>`
+    #[arg(long, conflicts_with = "all")]
+    example: Option<usize>,
+    /// Part to solve, `1`, `2` or `both`
+    #[arg(long, value_enum, default_value_t)]
+    part: solutions::Parts,
+    /// Output format, `json` and `csv` print one record per part
+    #[arg(long, value_enum, default_value_t)]
+    format: solutions::Format,
+    /// Parameter for the solvers that read one, e.g. `unfold=10` for day 12 of 2023
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
 }
 
 #[derive(Args, Debug)]
 struct DownloadCommand {
     day: Option<usize>,
+    /// Template for the solution, if it doesn't exist yet
+    #[arg(long, value_enum, default_value_t)]
+    template: Template,
+}
+
+#[derive(Args, Debug)]
+struct NewCommand {
+    day: usize,
+    /// Shape of the input the solution starts from
+    #[arg(long, value_enum, default_value_t)]
+    template: Template,
+    /// Overwrite the day's solution file if it already exists
+    #[arg(long)]
+    force: bool,
+}
+
+/// Solution templates in `src/solutions/templates`, by input shape.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+enum Template {
+    /// One number per line
+    #[default]
+    Numbers,
+    /// One string per line
+    Lines,
+    /// Grid of characters, one tile per char
+    Grid,
+    /// Blocks of lines separated by blank lines
+    Blocks,
+    /// One record per line, parsed with `FromStr`
+    Records,
+}
+
+impl Template {
+    fn path(self) -> &'static str {
+        match self {
+            Template::Numbers => "src/solutions/templates/numbers.rs",
+            Template::Lines => "src/solutions/templates/lines.rs",
+            Template::Grid => "src/solutions/templates/grid.rs",
+            Template::Blocks => "src/solutions/templates/blocks.rs",
+            Template::Records => "src/solutions/templates/records.rs",
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+struct VerifyCommand {
+    day: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+struct SubmitCommand {
+    day: usize,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+}
+
+#[derive(Args, Debug)]
+struct BenchCommand {
+    /// A single day (`5`) or a range of days (`3..=9`, `3..10`)
+    #[arg(value_parser = parse_days)]
+    day: Option<RangeInclusive<usize>>,
+    /// Benchmark every implemented day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Part to benchmark, `1`, `2` or `both`
+    #[arg(long, value_enum, default_value_t)]
+    part: solutions::Parts,
+    /// Runs discarded before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+    /// Measured runs
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+    /// Compare the medians against a baseline saved with `--save-baseline`
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Save the medians of this run as a baseline
+    #[arg(long)]
+    save_baseline: Option<PathBuf>,
+    /// Slowdown over the baseline, in percent, that gets flagged and fails the run
+    #[arg(long, default_value_t = 10.)]
+    threshold: f64,
+    /// Parameter for the solvers that read one, e.g. `unfold=10` for day 12 of 2023
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() {
     dotenv::dotenv().ok();
 
-    let args = CLIArgs::parse();
+    if let Err(e) = execute(CLIArgs::parse()).await {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
 
-    match args {
-        CLIArgs::Run(c) => {
-            let day = get_day(c.day);
-            solutions::solve(day, c.part);
+async fn execute(args: CLIArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let year = get_year(args.year)?;
+    let clock = schedule::SystemClock;
+    match args.command {
+        Command::Run(c) => run(year, c, &clock),
+        Command::Download(c) => {
+            download(year, get_download_day(year, c.day, &clock)?, c.template).await
+        }
+        Command::Verify(c) => verify(year, c.day),
+        Command::Submit(c) => submit(year, c.day, c.part as usize).await,
+        Command::New(c) => {
+            if c.day == 0 || c.day > schedule::days_in(year) {
+                return Err(format!("{year} has no day {}", c.day).into());
+            }
+            scaffold(year, c.day, c.template, c.force)
+        }
+        Command::Bench(c) => {
+            let days = match c.day {
+                _ if c.all => 1..=25,
+                Some(days) => days,
+                None => {
+                    let day = get_day(year, None, &clock)?;
+                    day..=day
+                }
+            };
+            let options = solutions::BenchOptions {
+                parts: c.part,
+                params: solutions::Params::new(c.params),
+                warmup: c.warmup,
+                runs: c.runs as usize,
+                baseline: c.baseline,
+                save_baseline: c.save_baseline,
+                threshold: c.threshold,
+            };
+
+            if solutions::bench(year, days, &options)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Command::List => {
+            for day in solutions::implemented_days(year) {
+                let input = solutions::input_path(year, day, None);
+                if input.exists() {
+                    println!("Day {:02}", day);
+                } else {
+                    println!("Day {:02} (missing {})", day, input.display());
+                }
+            }
 
             Ok(())
         }
-        CLIArgs::Download(c) => download(get_day(c.day)).await,
     }
 }
 
-async fn download(selected_day: usize) -> Result<(), Box<dyn std::error::Error>> {
-    let mut headers = reqwest::header::HeaderMap::new();
+fn run(
+    year: usize,
+    c: RunCommand,
+    clock: &dyn schedule::Clock,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let params = solutions::Params::new(c.params);
+    let variant = c.variant.as_deref();
+    let day = match c.day {
+        _ if c.all => {
+            solutions::solve_range(year, 1..=25, variant, c.part, &params, c.format)?;
+            return Ok(());
+        }
+        Some(days) if days.start() != days.end() => {
+            if c.input.is_some() || c.stdin || c.example.is_some() {
+                return Err(
+                    "--input, --stdin and --example can only be used with a single day".into(),
+                );
+            }
+            solutions::solve_range(year, days, variant, c.part, &params, c.format)?;
+            return Ok(());
+        }
+        day => get_day(year, day.map(|d| *d.start()), clock)?,
+    };
 
-    let token = std::env::var("TOKEN");
-    if let Err(_) = token {
-        panic!("Missing TOKEN env variable");
-    }
-    let cookie = format!("session={}", token?);
-    headers.insert("cookie", reqwest::header::HeaderValue::from_str(&cookie)?);
+    let source = if c.stdin {
+        solutions::Source::Stdin
+    } else if let Some(example) = c.example {
+        solutions::Source::File(solutions::example_path(year, day, example))
+    } else {
+        solutions::Source::File(
+            c.input
+                .unwrap_or_else(|| solutions::input_path(year, day, variant)),
+        )
+    };
+    solutions::solve(year, day, &source, c.part, &params, c.format)?;
+
+    Ok(())
+}
 
-    let client = reqwest::Client::builder()
-        .default_headers(headers)
-        .build()?;
+fn verify(year: usize, day: Option<usize>) -> Result<(), Box<dyn std::error::Error>> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => solutions::implemented_days(year).collect(),
+    };
 
-    let body = client
-        .get(format!(
-            "https://adventofcode.com/2023/day/{selected_day}/input"
-        ))
-        .send()
-        .await?
-        .text()
-        .await?;
+    // The recorded answers are for the puzzle as given, without `--param`
+    let params = solutions::Params::default();
+    let (mut passed, mut failed, mut missing, mut unimplemented) = (0, 0, 0, 0);
+    for day in days {
+        if solutions::get(year, day).is_none() {
+            println!("Day {:02}: not implemented", day);
+            unimplemented += 2;
+            continue;
+        }
+        let expected = answers::load(year, day)?;
+        let parts = match (expected.get(1), expected.get(2)) {
+            (Some(_), Some(_)) => solutions::Parts::Both,
+            (Some(_), None) => solutions::Parts::First,
+            (None, Some(_)) => solutions::Parts::Second,
+            (None, None) => {
+                println!("Day {:02}: missing", day);
+                missing += 2;
+                continue;
+            }
+        };
+        let source = solutions::Source::File(solutions::input_path(year, day, None));
+        let results = match solutions::run(year, day, &source, parts, &params) {
+            Some(Ok(results)) => results,
+            Some(Err(e)) => {
+                println!("Day {:02}: FAIL {e}", day);
+                failed += 1;
+                continue;
+            }
+            None => unreachable!("day {:02} is implemented", day),
+        };
 
-    if body.contains("Please don't repeatedly request this endpoint") {
-        println!("Day {:02} not yet ready", selected_day);
+        for part in 1..=2 {
+            let Some(expected) = expected.get(part) else {
+                println!("Day {:02} part {}: missing", day, part);
+                missing += 1;
+                continue;
+            };
+            let result = results.parts.iter().find(|r| r.part == part).unwrap();
+            match &result.answer {
+                Ok(answer) if answer == expected => {
+                    println!("Day {:02} part {}: pass", day, part);
+                    passed += 1;
+                }
+                Ok(answer) => {
+                    println!(
+                        "Day {:02} part {}: FAIL expected {}, got {}",
+                        day, part, expected, answer
+                    );
+                    failed += 1;
+                }
+                Err(solutions::PartError::Unimplemented) => {
+                    println!("Day {:02} part {}: not implemented", day, part);
+                    unimplemented += 1;
+                }
+                Err(e) => {
+                    println!("Day {:02} part {}: FAIL {}", day, part, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "{passed} passed, {failed} failed, {missing} missing, {unimplemented} not implemented"
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+async fn submit(year: usize, day: usize, part: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let mut answers = answers::load(year, day)?;
+    if let Some(accepted) = answers.get(part) {
+        println!(
+            "Day {:02} part {}: already accepted {}",
+            day, part, accepted
+        );
         return Ok(());
     }
 
-    std::fs::write(format!("inputs/{:02}", selected_day), body)?;
+    let source = solutions::Source::File(solutions::input_path(year, day, None));
+    let result = solutions::run(
+        year,
+        day,
+        &source,
+        solutions::Parts::only(part),
+        &solutions::Params::default(),
+    )
+    .ok_or_else(|| format!("day {:02} is not implemented", day))??;
+    let answer = result.parts[0]
+        .answer
+        .as_ref()
+        .map_err(|e| format!("part {part} {e}"))?;
 
-    copy_template(selected_day)?;
+    if let Some(reason) = attempts::refusal(&attempts::load(year, day)?, part, answer) {
+        return Err(format!("not submitting, {reason}").into());
+    }
 
-    append_day(selected_day)?;
+    let verdict = aoc::Client::from_env()?
+        .submit(year, day, part, answer)
+        .await?;
+    attempts::record(year, day, part, answer, &verdict)?;
+    println!("Day {:02} part {}: {} is {}", day, part, answer, verdict);
 
+    if verdict == aoc::Verdict::Correct {
+        answers.set(part, answer);
+        answers::save(year, day, &answers)?;
+    }
     Ok(())
 }
 
-fn copy_template(selected_day: usize) -> Result<u64, std::io::Error> {
-    return std::fs::copy(
-        "src/solutions/template.rs",
-        format!("src/solutions/day{:02}.rs", selected_day),
+async fn download(
+    year: usize,
+    selected_day: usize,
+    template: Template,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client = aoc::Client::from_env()?;
+
+    let body = client.input(year, selected_day).await?;
+
+    let input = solutions::input_path(year, selected_day, None);
+    std::fs::create_dir_all(input.parent().unwrap())?;
+    std::fs::write(input, body)?;
+
+    download_puzzle(&client, year, selected_day).await?;
+
+    scaffold(year, selected_day, template, false)
+}
+
+/// Stores the examples of the puzzle page as `examples/YYYY/NN-K`, and the
+/// answers given so far in the answers file.
+async fn download_puzzle(
+    client: &aoc::Client,
+    year: usize,
+    selected_day: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let puzzle = client.puzzle(year, selected_day).await?;
+
+    std::fs::create_dir_all(format!("examples/{year}"))?;
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        std::fs::write(solutions::example_path(year, selected_day, i + 1), example)?;
+    }
+    println!(
+        "Day {:02}: saved {} examples",
+        selected_day,
+        puzzle.examples.len()
     );
+
+    if !puzzle.answers.is_empty() {
+        let mut answers = answers::load(year, selected_day)?;
+        for (i, answer) in puzzle.answers.iter().take(2).enumerate() {
+            answers.set(i + 1, answer);
+        }
+        answers::save(year, selected_day, &answers)?;
+        println!(
+            "Day {:02}: saved {} answers",
+            selected_day,
+            puzzle.answers.len().min(2)
+        );
+    }
+
+    Ok(())
 }
 
-fn append_day(selected_day: usize) -> Result<(), std::io::Error> {
-    let mut file = std::fs::File::open("src/solutions/mod.rs")?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
+/// Creates the day's solution from a template and registers it. An existing
+/// solution is only replaced with `force`, so this can safely be re-run.
+fn scaffold(
+    year: usize,
+    selected_day: usize,
+    template: Template,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let solution = format!("src/solutions/y{year}/day{:02}.rs", selected_day);
+    if std::path::Path::new(&solution).exists() && !force {
+        println!("Keeping existing {solution}, use --force to replace it");
+    } else {
+        std::fs::create_dir_all(format!("src/solutions/y{year}"))?;
+        std::fs::copy(template.path(), &solution)?;
+        println!("Created {solution}");
+    }
 
-    let new_mod = format!("mod day{:02};", selected_day);
-    contents = contents.replace("mod solver;", &format!("{new_mod}\nmod solver;"));
+    registry::register_day(std::path::Path::new("."), year, selected_day)?;
 
-    let new_match = format!(
-        "{selected_day} => day{:02}::Problem.solve(filename, parts),",
-        selected_day
-    );
-    contents = contents.replace("_ => panic!", &format!("{new_match}\n        _ => panic!"));
+    Ok(())
+}
+
+/// The `--year` option, falling back to the `AOC_YEAR` env variable.
+fn get_year(year: Option<usize>) -> Result<usize, String> {
+    let year = match year {
+        Some(year) => year,
+        None => match std::env::var("AOC_YEAR") {
+            Ok(year) => year
+                .parse()
+                .map_err(|_| format!("invalid AOC_YEAR \"{year}\""))?,
+            Err(_) => DEFAULT_YEAR,
+        },
+    };
 
-    return std::fs::write("src/solutions/mod.rs", contents);
+    if year < 2015 {
+        return Err(format!("there is no Advent of Code {year}"));
+    }
+    Ok(year)
+}
+
+/// The given day, or today's puzzle if it's implemented, or else the latest
+/// implemented day.
+fn get_day(year: usize, day: Option<usize>, clock: &dyn schedule::Clock) -> Result<usize, String> {
+    match day {
+        Some(day) => Ok(day),
+        None => schedule::default_day(year, solutions::implemented_days(year), clock)
+            .ok_or_else(|| format!("no days of {year} are implemented yet")),
+    }
+}
+
+/// The given day, or the latest unlocked one, refusing days that are still locked.
+fn get_download_day(
+    year: usize,
+    day: Option<usize>,
+    clock: &dyn schedule::Clock,
+) -> Result<usize, String> {
+    let day = match day {
+        Some(day) if day == 0 || day > schedule::days_in(year) => {
+            return Err(format!("{year} has no day {day}"));
+        }
+        Some(day) => day,
+        None => schedule::latest_unlocked(year, clock).unwrap_or(1),
+    };
+
+    match schedule::time_until_unlock(year, day, clock) {
+        Some(remaining) => Err(format!(
+            "day {:02} of {year} unlocks in {}",
+            day,
+            schedule::format_countdown(remaining)
+        )),
+        None => Ok(day),
+    }
+}
+
+fn parse_param(value: &str) -> Result<(String, String), String> {
+    let (key, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got \"{value}\""))?;
+    Ok((key.to_owned(), value.to_owned()))
 }
 
-fn get_day(day: Option<usize>) -> usize {
-    let default_day = chrono::Utc::now().day() as usize;
-    day.unwrap_or(default_day)
+fn parse_days(value: &str) -> Result<RangeInclusive<usize>, String> {
+    let parse = |v: &str| {
+        v.parse::<usize>()
+            .map_err(|_| format!("invalid day \"{v}\""))
+    };
+
+    let (start, end) = if let Some((start, end)) = value.split_once("..=") {
+        (parse(start)?, parse(end)?)
+    } else if let Some((start, end)) = value.split_once("..") {
+        (
+            parse(start)?,
+            parse(end)?.checked_sub(1).ok_or("day 0 doesn't exist")?,
+        )
+    } else {
+        let day = parse(value)?;
+        (day, day)
+    };
+    if start == 0 {
+        return Err("day 0 doesn't exist".to_owned());
+    }
+
+    let days = start..=end;
+    if days.is_empty() {
+        return Err(format!("empty day range \"{value}\""));
+    }
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_days_and_ranges() {
+        assert_eq!(parse_days("5"), Ok(5..=5));
+        assert_eq!(parse_days("3..=9"), Ok(3..=9));
+        assert_eq!(parse_days("3..10"), Ok(3..=9));
+        assert_eq!(parse_days("3..4"), Ok(3..=3));
+    }
+
+    #[test]
+    fn rejects_bad_day_ranges() {
+        assert_eq!(
+            parse_days("3..3"),
+            Err("empty day range \"3..3\"".to_owned())
+        );
+        assert_eq!(
+            parse_days("10..=3"),
+            Err("empty day range \"10..=3\"".to_owned())
+        );
+        assert_eq!(parse_days("..5"), Err("invalid day \"\"".to_owned()));
+        assert_eq!(parse_days("3.."), Err("invalid day \"\"".to_owned()));
+        assert_eq!(parse_days("x"), Err("invalid day \"x\"".to_owned()));
+        assert_eq!(parse_days("0..1"), Err("day 0 doesn't exist".to_owned()));
+        assert_eq!(parse_days("0"), Err("day 0 doesn't exist".to_owned()));
+        assert_eq!(parse_days("1..0"), Err("day 0 doesn't exist".to_owned()));
+    }
 }
@@ -0,0 +1,162 @@
+use itertools::Itertools;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+/// Adds the day to its year's `days!` registry in the crate at `root`, creating
+/// the year's module and adding it to the `years!` registry for a new year.
+/// Registering a day again changes nothing.
+pub fn register_day(root: &Path, year: usize, day: usize) -> std::io::Result<()> {
+    let solutions = root.join("src/solutions");
+    let year_module = solutions.join(format!("y{year}/mod.rs"));
+    if !year_module.exists() {
+        std::fs::create_dir_all(solutions.join(format!("y{year}")))?;
+        std::fs::write(&year_module, "days! {\n}\n")?;
+        update(
+            &solutions.join("mod.rs"),
+            "years! {\n",
+            &format!("{year} => y{year},"),
+        )?;
+    }
+
+    update(
+        &year_module,
+        "days! {\n",
+        &format!("{day} => day{:02},", day),
+    )
+}
+
+/// Adds `entry` to the registry in the file at `path`, only writing the file
+/// if the entry is new.
+fn update(path: &Path, registry: &str, entry: &str) -> std::io::Result<()> {
+    let contents = std::fs::read_to_string(path)?;
+    match add_entry(&contents, registry, entry) {
+        Ok(Some(contents)) => std::fs::write(path, contents),
+        Ok(None) => Ok(()),
+        Err(e) => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("{e} in {}", path.display()),
+        )),
+    }
+}
+
+/// Inserts `entry` into the registry opened by `registry` in `contents`,
+/// keeping the entries sorted by their leading number. `None` if the entry is
+/// already there.
+fn add_entry(contents: &str, registry: &str, entry: &str) -> Result<Option<String>, String> {
+    let name = registry.trim_end_matches(" {\n");
+    let missing_registry = || format!("{name} registry not found");
+
+    let start = contents.find(registry).ok_or_else(missing_registry)? + registry.len();
+    let end = start + contents[start..].find('}').ok_or_else(missing_registry)?;
+
+    let mut entries = contents[start..end]
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect_vec();
+    if entries.contains(&entry) {
+        return Ok(None);
+    }
+    entries.push(entry);
+    entries.sort_by_key(|entry| {
+        entry
+            .split(' ')
+            .next()
+            .and_then(|number| number.parse::<usize>().ok())
+    });
+
+    let registry: String = entries
+        .iter()
+        .map(|entry| format!("    {entry}\n"))
+        .collect();
+    Ok(Some(format!(
+        "{}{}{}",
+        &contents[..start],
+        registry,
+        &contents[end..]
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const YEARS: &str = "mod solver;\n\nyears! {\n    2023 => y2023,\n}\n\npub fn solve() {}\n";
+
+    /// An empty directory for a test's crate, removed when dropped.
+    struct Root(PathBuf);
+
+    impl Root {
+        fn new(name: &str, solutions: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("registry-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(path.join("src/solutions")).unwrap();
+            std::fs::write(path.join("src/solutions/mod.rs"), solutions).unwrap();
+            Root(path)
+        }
+
+        fn read(&self, file: &str) -> String {
+            std::fs::read_to_string(self.0.join("src/solutions").join(file)).unwrap()
+        }
+    }
+
+    impl Drop for Root {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn inserts_entries_in_order() {
+        let days = "days! {\n    1 => day01,\n    12 => day12,\n}\n";
+        assert_eq!(
+            add_entry(days, "days! {\n", "3 => day03,"),
+            Ok(Some(
+                "days! {\n    1 => day01,\n    3 => day03,\n    12 => day12,\n}\n".to_owned()
+            ))
+        );
+        assert_eq!(
+            add_entry("days! {\n}\n", "days! {\n", "3 => day03,"),
+            Ok(Some("days! {\n    3 => day03,\n}\n".to_owned()))
+        );
+    }
+
+    #[test]
+    fn skips_repeated_entries() {
+        let days = "days! {\n    1 => day01,\n    12 => day12,\n}\n";
+        assert_eq!(add_entry(days, "days! {\n", "12 => day12,"), Ok(None));
+    }
+
+    #[test]
+    fn registers_days_of_new_years() {
+        let root = Root::new("new-year", YEARS);
+
+        register_day(&root.0, 2024, 5).unwrap();
+        register_day(&root.0, 2024, 5).unwrap();
+        register_day(&root.0, 2024, 2).unwrap();
+
+        assert_eq!(
+            root.read("mod.rs"),
+            "mod solver;\n\n\
+             years! {\n    2023 => y2023,\n    2024 => y2024,\n}\n\n\
+             pub fn solve() {}\n"
+        );
+        assert_eq!(
+            root.read("y2024/mod.rs"),
+            "days! {\n    2 => day02,\n    5 => day05,\n}\n"
+        );
+    }
+
+    #[test]
+    fn rejects_files_without_registry() {
+        let root = Root::new("missing", "mod solver;\n");
+
+        let e = register_day(&root.0, 2024, 5).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert!(
+            e.to_string().starts_with("years! registry not found in "),
+            "{e}"
+        );
+    }
+}
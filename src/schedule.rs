@@ -0,0 +1,123 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+/// Source of the current time, so the schedule can be tested.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Number of puzzles in a year's calendar.
+pub fn days_in(year: usize) -> usize {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Puzzles unlock at midnight US Eastern, which is UTC-5 in December.
+pub fn unlock_time(year: usize, day: usize) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year as i32, 12, day as u32, 5, 0, 0)
+        .unwrap()
+}
+
+/// How long until the day unlocks, or `None` if it already has.
+pub fn time_until_unlock(year: usize, day: usize, clock: &dyn Clock) -> Option<Duration> {
+    let remaining = unlock_time(year, day) - clock.now();
+    (remaining > Duration::zero()).then_some(remaining)
+}
+
+/// The most recently unlocked day of the year, if any.
+pub fn latest_unlocked(year: usize, clock: &dyn Clock) -> Option<usize> {
+    (1..=days_in(year))
+        .rev()
+        .find(|day| time_until_unlock(year, *day, clock).is_none())
+}
+
+/// The day to run when none is given: today's puzzle if it's implemented,
+/// otherwise the latest implemented day.
+pub fn default_day(
+    year: usize,
+    implemented: impl Iterator<Item = usize>,
+    clock: &dyn Clock,
+) -> Option<usize> {
+    let implemented: Vec<_> = implemented.collect();
+    latest_unlocked(year, clock)
+        .filter(|day| implemented.contains(day))
+        .or_else(|| implemented.last().copied())
+}
+
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.num_seconds();
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m {seconds}s")
+    } else {
+        format!("{hours}h {minutes}m {seconds}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn at(month: u32, day: u32, hour: u32) -> FixedClock {
+        FixedClock(Utc.with_ymd_and_hms(2023, month, day, hour, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        assert_eq!(latest_unlocked(2023, &at(12, 5, 4)), Some(4));
+        assert_eq!(latest_unlocked(2023, &at(12, 5, 5)), Some(5));
+        assert_eq!(
+            time_until_unlock(2023, 6, &at(12, 5, 17)),
+            Some(Duration::hours(12))
+        );
+    }
+
+    #[test]
+    fn outside_december() {
+        assert_eq!(latest_unlocked(2023, &at(11, 30, 12)), None);
+        assert_eq!(latest_unlocked(2023, &at(12, 1, 4)), None);
+        assert_eq!(latest_unlocked(2022, &at(3, 1, 0)), Some(25));
+        assert_eq!(latest_unlocked(2023, &at(12, 31, 0)), Some(25));
+    }
+
+    #[test]
+    fn defaults_to_latest_implemented_day() {
+        let clock = at(12, 14, 12);
+        assert_eq!(default_day(2023, 1..=14, &clock), Some(14));
+        assert_eq!(default_day(2023, 1..=13, &clock), Some(13));
+        assert_eq!(
+            default_day(2023, [1, 3, 14].into_iter(), &at(12, 3, 12)),
+            Some(3)
+        );
+        assert_eq!(default_day(2023, std::iter::empty(), &clock), None);
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(Duration::seconds(3725)), "1h 2m 5s");
+        assert_eq!(format_countdown(Duration::hours(50)), "2d 2h 0m 0s");
+    }
+}
@@ -0,0 +1,253 @@
+use super::report::{get_elapsed_precise, print_grid};
+use super::{check_params, get, input_path, Params, Parts, Source};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+pub struct BenchOptions {
+    pub parts: Parts,
+    pub params: Params,
+    pub warmup: usize,
+    pub runs: usize,
+    /// Baseline to compare the medians against
+    pub baseline: Option<PathBuf>,
+    /// Where to save the medians of this run as a new baseline
+    pub save_baseline: Option<PathBuf>,
+    /// Slowdown over the baseline, in percent, that gets flagged
+    pub threshold: f64,
+}
+
+struct Stats {
+    min: f64,
+    median: f64,
+    mean: f64,
+    stddev: f64,
+}
+
+impl Stats {
+    fn new(samples: &mut [f64]) -> Self {
+        samples.sort_by(|a, b| a.total_cmp(b));
+
+        let n = samples.len();
+        let median = (samples[(n - 1) / 2] + samples[n / 2]) / 2.;
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Repeatedly parses and solves each day, reporting timing statistics for
+/// every step. Returns whether any step was flagged as slower than the baseline.
+pub fn bench(
+    year: usize,
+    days: RangeInclusive<usize>,
+    options: &BenchOptions,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    check_params(year, days.clone(), &options.params)?;
+    let baseline = match &options.baseline {
+        Some(path) => load_baseline(path)?,
+        None => HashMap::new(),
+    };
+
+    let mut measurements = Vec::new();
+    for day in days {
+        let Some(solver) = get(year, day) else {
+            continue;
+        };
+        // Read the input once, so the parse times don't include disk access
+        let filename = input_path(year, day, None);
+        // Days whose input can't be read or parsed are skipped, like parts below
+        let parsed = Source::File(filename.clone())
+            .read_to_string()
+            .and_then(|contents| {
+                let (input, _) = solver
+                    .parse(&mut contents.as_bytes(), &options.params)
+                    .map_err(|e| e.in_file(&filename))?;
+                Ok((contents, input))
+            });
+        let (contents, input) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Day {:02}: {e}, skipping", day);
+                continue;
+            }
+        };
+
+        // Parts without an answer, such as unimplemented ones, aren't benchmarked
+        let parts = options
+            .parts
+            .numbers()
+            .filter(|part| match solver.solve_part(&input, *part).answer {
+                Ok(_) => true,
+                Err(e) => {
+                    eprintln!("Day {:02} part {}: {e}, skipping", day, part);
+                    false
+                }
+            })
+            .collect_vec();
+
+        let mut samples = vec![Vec::with_capacity(options.runs); parts.len() + 1];
+        for run in 0..(options.warmup + options.runs) {
+            let (input, parse_elapsed) = solver
+                .parse(&mut contents.as_bytes(), &options.params)
+                .map_err(|e| e.in_file(&filename))?;
+            let part_elapsed = parts
+                .iter()
+                .map(|part| solver.solve_part(&input, *part).elapsed)
+                .collect_vec();
+
+            if run >= options.warmup {
+                samples[0].push(parse_elapsed.as_nanos() as f64);
+                for (i, elapsed) in part_elapsed.into_iter().enumerate() {
+                    samples[i + 1].push(elapsed.as_nanos() as f64);
+                }
+            }
+        }
+
+        let steps = std::iter::once("input".to_owned()).chain(parts.iter().map(|p| p.to_string()));
+        for (step, mut samples) in steps.zip(samples) {
+            measurements.push((day, step, Stats::new(&mut samples)));
+        }
+    }
+
+    let mut slower = false;
+    let rows = measurements
+        .iter()
+        .map(|(day, step, stats)| {
+            let comparison = match baseline.get(&(*day, step.to_owned())) {
+                Some(base) => match change(*base, stats.median) {
+                    Some(change) if change > options.threshold => {
+                        slower = true;
+                        format!("{:+.1}% SLOWER", change)
+                    }
+                    Some(change) => format!("{:+.1}%", change),
+                    None => "zero baseline".to_owned(),
+                },
+                None => String::new(),
+            };
+
+            vec![
+                format!("{:02}", day),
+                step.to_owned(),
+                get_elapsed_precise(stats.min),
+                get_elapsed_precise(stats.median),
+                get_elapsed_precise(stats.mean),
+                get_elapsed_precise(stats.stddev),
+                comparison,
+            ]
+        })
+        .collect_vec();
+
+    println!("{} runs after {} warmup runs", options.runs, options.warmup);
+    print_grid(
+        &["Day", "Part", "Min", "Median", "Mean", "Stddev", "Baseline"],
+        &rows,
+        2,
+    );
+
+    if let Some(path) = &options.save_baseline {
+        std::fs::write(path, format_baseline(&measurements))?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    Ok(slower)
+}
+
+/// Change of the median over the baseline, in percent, or `None` for a zero
+/// baseline that nothing can be compared against.
+fn change(base: f64, median: f64) -> Option<f64> {
+    (base != 0.).then(|| (median - base) / base * 100.)
+}
+
+/// One `day step median_ns` line per measurement.
+fn format_baseline(measurements: &[(usize, String, Stats)]) -> String {
+    measurements
+        .iter()
+        .map(|(day, step, stats)| format!("{:02} {} {}\n", day, step, stats.median))
+        .collect()
+}
+
+/// Reads the `day step median_ns` lines written by `--save-baseline`.
+fn load_baseline(path: &Path) -> std::io::Result<HashMap<(usize, String), f64>> {
+    parse_baseline(&std::fs::read_to_string(path)?, path)
+}
+
+fn parse_baseline(contents: &str, path: &Path) -> std::io::Result<HashMap<(usize, String), f64>> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (day, step, median) = line
+                .split_whitespace()
+                .collect_tuple()
+                .and_then(|(day, step, median)| {
+                    Some((day.parse().ok()?, step.to_owned(), median.parse().ok()?))
+                })
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("{}: invalid line \"{line}\"", path.display()),
+                    )
+                })?;
+            Ok(((day, step), median))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_stats() {
+        let stats = Stats::new(&mut [4., 1., 3., 2.]);
+        assert_eq!(stats.min, 1.);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        // Sample stddev, divided by n - 1
+        assert!((stats.stddev - (5f64 / 3.).sqrt()).abs() < 1e-9);
+
+        let stats = Stats::new(&mut [7., 1., 4.]);
+        assert_eq!(stats.median, 4.);
+
+        let stats = Stats::new(&mut [5.]);
+        assert_eq!((stats.median, stats.stddev), (5., 0.));
+    }
+
+    #[test]
+    fn compares_against_baseline() {
+        assert_eq!(change(200., 250.), Some(25.));
+        assert_eq!(change(200., 150.), Some(-25.));
+        assert_eq!(change(0., 150.), None);
+    }
+
+    #[test]
+    fn round_trips_baselines() {
+        let path = Path::new("baseline");
+        let measurements = vec![
+            (3, "input".to_owned(), Stats::new(&mut [1500.])),
+            (12, "2".to_owned(), Stats::new(&mut [1., 2.])),
+        ];
+        let contents = format_baseline(&measurements);
+        assert_eq!(contents, "03 input 1500\n12 2 1.5\n");
+
+        let baseline = parse_baseline(&contents, path).unwrap();
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&(3, "input".to_owned())], 1500.);
+        assert_eq!(baseline[&(12, "2".to_owned())], 1.5);
+
+        let e = parse_baseline("03 input\n", path).unwrap_err();
+        assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,40 @@
+use super::{ErasedSolver, Params, Parts};
+
+/// Declares a day's worked examples as tests, one module per example with a
+/// test per part. Use `_` for a part the example has no answer for.
+///
+/// ```ignore
+/// examples! {
+///     example: EXAMPLE => (142, _),
+/// }
+/// ```
+macro_rules! examples {
+    ($($name:ident: $input:expr => ($part1:tt, $part2:tt),)*) => {
+        $(
+            mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                examples!(@part part1, 1, $input, $part1);
+                examples!(@part part2, 2, $input, $part2);
+            }
+        )*
+    };
+    (@part $test:ident, $part:literal, $input:expr, _) => {
+        // Still refer to the input, so examples without answers yet aren't dead code
+        const _: &str = $input;
+    };
+    (@part $test:ident, $part:literal, $input:expr, $expected:tt) => {
+        #[test]
+        fn $test() {
+            $crate::solutions::examples::check_example(&Problem, $input, $part, $expected);
+        }
+    };
+}
+
+pub fn check_example(solver: &dyn ErasedSolver, input: &str, part: usize, expected: impl ToString) {
+    let result = solver
+        .solve_str(input, Parts::only(part), &Params::default())
+        .unwrap_or_else(|e| panic!("{e}"));
+    assert_eq!(result.parts[0].answer, Ok(expected.to_string()));
+}
@@ -1,36 +1,191 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
+mod bench;
+#[cfg(test)]
+#[macro_use]
+mod examples;
+mod params;
+mod parse;
+mod report;
 mod solver;
 
-pub use solver::Solver;
-
-pub fn solve(day: usize, parts: usize) {
-    let filename = format!("inputs/{:02}", day);
-    match day {
-        1 => day01::Problem.solve(filename, parts),
-        2 => day02::Problem.solve(filename, parts),
-        3 => day03::Problem.solve(filename, parts),
-        4 => day04::Problem.solve(filename, parts),
-        5 => day05::Problem.solve(filename, parts),
-        6 => day06::Problem.solve(filename, parts),
-        7 => day07::Problem.solve(filename, parts),
-        8 => day08::Problem.solve(filename, parts),
-        9 => day09::Problem.solve(filename, parts),
-        10 => day10::Problem.solve(filename, parts),
-        11 => day11::Problem.solve(filename, parts),
-        12 => day12::Problem.solve(filename, parts),
-        13 => day13::Problem.solve(filename, parts),
-        _ => panic!("day not implemented"),
+use itertools::Itertools;
+use std::io::Read;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+pub use bench::{bench, BenchOptions};
+pub use params::Params;
+pub use parse::ParseError;
+pub use report::Format;
+pub use solver::{DayResult, ErasedSolver, PartError, Parts, Solver};
+
+/// Declares a year's days, in `src/solutions/yYYYY/mod.rs`.
+macro_rules! days {
+    ($($day:literal => $module:ident,)*) => {
+        $(mod $module;)*
+
+        /// Every implemented day, in order.
+        pub static DAYS: &[(usize, &(dyn $crate::solutions::ErasedSolver + Sync))] =
+            &[$(($day, &$module::Problem)),*];
+    };
+}
+
+macro_rules! years {
+    ($($year:literal => $module:ident,)*) => {
+        $(mod $module;)*
+
+        /// The days of every year with solutions, in order.
+        static YEARS: &[(usize, &[(usize, &(dyn ErasedSolver + Sync))])] =
+            &[$(($year, $module::DAYS)),*];
+    };
+}
+
+years! {
+    2023 => y2023,
+}
+
+pub fn solve(
+    year: usize,
+    day: usize,
+    source: &Source,
+    parts: Parts,
+    params: &Params,
+    format: Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    check_params(year, day..=day, params)?;
+    let result = run(year, day, source, parts, params)
+        .ok_or_else(|| format!("day {:02} of {year} is not implemented", day))?;
+    match (format, result) {
+        (Format::Text, result) => report::print_day(&result?),
+        (_, Ok(result)) => report::print_records(format, &[(day, result)]),
+        (_, Err(e)) => {
+            // Still a record per part, so the output shows why the day failed
+            report::print_records(format, &[(day, DayResult::unparsed(&e, parts))]);
+            return Err(e.into());
+        }
+    }
+    Ok(())
+}
+
+pub fn solve_range(
+    year: usize,
+    days: RangeInclusive<usize>,
+    variant: Option<&str>,
+    parts: Parts,
+    params: &Params,
+    format: Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    check_params(year, days.clone(), params)?;
+    let mut failed = 0;
+    let results = implemented_days(year)
+        .filter(|day| days.contains(day))
+        .filter_map(|day| {
+            match run(
+                year,
+                day,
+                &Source::File(input_path(year, day, variant)),
+                parts,
+                params,
+            )? {
+                Ok(result) => Some((day, result)),
+                Err(e) => {
+                    eprintln!("Day {:02}: {e}", day);
+                    failed += 1;
+                    // The table leaves the day out, records show why it failed
+                    (format != Format::Text).then(|| (day, DayResult::unparsed(&e, parts)))
+                }
+            }
+        })
+        .collect_vec();
+    match format {
+        Format::Text => report::print_table(&results),
+        _ => report::print_records(format, &results),
+    }
+    if failed > 0 {
+        return Err(format!("{failed} of the days couldn't read or parse their input").into());
+    }
+    Ok(())
+}
+
+/// Errors on parameters that none of the implemented `days` accept, so a
+/// misspelled key isn't silently ignored.
+fn check_params(year: usize, days: RangeInclusive<usize>, params: &Params) -> Result<(), String> {
+    let accepted = days
+        .filter_map(|day| get(year, day))
+        .flat_map(|solver| solver.params().iter().copied())
+        .unique()
+        .collect_vec();
+    params.check(&accepted)
+}
+
+fn days(year: usize) -> &'static [(usize, &'static (dyn ErasedSolver + Sync))] {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |(_, days)| days)
+}
+
+pub fn get(year: usize, day: usize) -> Option<&'static dyn ErasedSolver> {
+    days(year)
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, solver)| *solver as &dyn ErasedSolver)
+}
+
+pub fn implemented_days(year: usize) -> impl Iterator<Item = usize> {
+    days(year).iter().map(|(day, _)| *day)
+}
+
+/// `inputs/YYYY/NN`, or `inputs/YYYY/NN.<variant>` for alternative inputs such
+/// as the puzzle examples.
+pub fn input_path(year: usize, day: usize, variant: Option<&str>) -> PathBuf {
+    match variant {
+        Some(variant) => format!("inputs/{year}/{:02}.{variant}", day).into(),
+        None => format!("inputs/{year}/{:02}", day).into(),
+    }
+}
+
+/// An example downloaded from the puzzle page, numbered from 1.
+pub fn example_path(year: usize, day: usize, example: usize) -> PathBuf {
+    format!("examples/{year}/{:02}-{example}", day).into()
+}
+
+/// Where a day's input is read from.
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    fn name(&self) -> &Path {
+        match self {
+            Source::File(filename) => filename,
+            Source::Stdin => Path::new("<stdin>"),
+        }
     }
+
+    pub fn read_to_string(&self) -> Result<String, ParseError> {
+        let result = match self {
+            Source::File(filename) => std::fs::read_to_string(filename),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|e| ParseError::new(format!("can't read input: {e}")).in_file(self.name()))
+    }
+}
+
+pub fn run(
+    year: usize,
+    day: usize,
+    source: &Source,
+    parts: Parts,
+    params: &Params,
+) -> Option<Result<DayResult, ParseError>> {
+    let solver = get(year, day)?;
+    Some(source.read_to_string().and_then(|input| {
+        solver
+            .solve_str(&input, parts, params)
+            .map_err(|e| e.in_file(source.name()))
+    }))
 }
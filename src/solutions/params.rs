@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+/// `KEY=VALUE` pairs given on the command line with `--param`, passed to the
+/// solvers that declare the key in [`Solver::PARAMS`](super::Solver::PARAMS).
+#[derive(Debug, Default, Clone)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+    pub fn new(params: Vec<(String, String)>) -> Self {
+        Params(params)
+    }
+
+    /// The parameter `name` parsed as `T`, or `default` if it wasn't given.
+    /// Later values win for repeated keys.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.0.iter().rev().find(|(key, _)| key == name) {
+            Some((_, value)) => value
+                .parse()
+                .map_err(|_| format!("invalid value \"{value}\" for parameter {name}")),
+            None => Ok(default),
+        }
+    }
+
+    /// Errors on the first key that isn't in `accepted`.
+    pub fn check(&self, accepted: &[&str]) -> Result<(), String> {
+        match self
+            .0
+            .iter()
+            .find(|(key, _)| !accepted.contains(&key.as_str()))
+        {
+            Some((key, _)) if accepted.is_empty() => Err(format!(
+                "unknown parameter {key}, no parameters are accepted"
+            )),
+            Some((key, _)) => Err(format!(
+                "unknown parameter {key}, expected one of: {}",
+                accepted.join(", ")
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> Params {
+        Params::new(
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn reads_values_with_defaults() {
+        let params = params(&[("unfold", "3"), ("unfold", "10")]);
+        assert_eq!(params.get("unfold", 5), Ok(10));
+        assert_eq!(params.get("age", 2), Ok(2));
+        assert_eq!(
+            params.get::<bool>("unfold", false),
+            Err("invalid value \"10\" for parameter unfold".to_owned())
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let params = params(&[("unfodl", "10")]);
+        assert_eq!(
+            params.check(&["unfold"]),
+            Err("unknown parameter unfodl, expected one of: unfold".to_owned())
+        );
+        assert_eq!(
+            params.check(&[]),
+            Err("unknown parameter unfodl, no parameters are accepted".to_owned())
+        );
+        assert_eq!(Params::default().check(&[]), Ok(()));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Error from `Solver::read_input`, pointing at the offending input line.
+///
+/// Line parsers (e.g. `FromStr` implementations) usually only know the message
+/// and maybe the column; the line and file are filled in by the callers.
+#[derive(Debug, Default)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// 1-based
+    pub line: Option<usize>,
+    /// 1-based, counted in chars
+    pub column: Option<usize>,
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// `index` is the 0-based char index within the line.
+    pub fn at_column(index: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            column: Some(index + 1),
+            ..ParseError::new(message)
+        }
+    }
+
+    /// `index` is the 0-based line index, as given by `enumerate`.
+    pub fn on_line(self, index: usize, text: &str) -> Self {
+        ParseError {
+            line: Some(index + 1),
+            text: Some(text.to_owned()),
+            ..self
+        }
+    }
+
+    pub fn in_file(self, file: &Path) -> Self {
+        ParseError {
+            file: Some(file.to_owned()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = [
+            self.file.as_ref().map(|file| file.display().to_string()),
+            self.line.map(|line| line.to_string()),
+            self.column.map(|column| column.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(":");
+
+        if location.is_empty() {
+            write!(f, "{}", self.message)?;
+        } else {
+            write!(f, "{location}: {}", self.message)?;
+        }
+        if let Some(text) = &self.text {
+            write!(f, "\n    {text}")?;
+            if let Some(column) = self.column {
+                write!(f, "\n    {}^", " ".repeat(column - 1))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        ParseError::new(message)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        ParseError::new(message)
+    }
+}
+
+impl From<std::num::ParseIntError> for ParseError {
+    fn from(e: std::num::ParseIntError) -> Self {
+        ParseError::new(e.to_string())
+    }
+}
+
+pub fn read_lines(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.map_err(|e| ParseError {
+                line: Some(i + 1),
+                ..ParseError::new(e.to_string())
+            })
+        })
+        .collect()
+}
+
+/// Parses each line with `parse`, attaching the line to any error.
+pub fn map_lines<T, E: Into<ParseError>>(
+    lines: &[String],
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.into().on_line(i, line)))
+        .collect()
+}
+
+/// Parses one `T` per line.
+pub fn parse_lines<T>(reader: impl BufRead) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    map_lines(&read_lines(reader)?, |line| line.parse())
+}
@@ -0,0 +1,314 @@
+use super::solver::{DayResult, PartResult};
+use clap::ValueEnum;
+use itertools::Itertools;
+use std::time::Duration;
+
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn print_day(result: &DayResult) {
+    println!("Parsed input ({})", get_elapsed(result.parse_elapsed));
+    for part in &result.parts {
+        print_result(part);
+    }
+    println!("Total: {}", get_elapsed(result.total_elapsed()));
+}
+
+fn print_result(result: &PartResult) {
+    match &result.answer {
+        Ok(res) => println!(
+            "Solution to part {}: {} ({})",
+            result.part,
+            res,
+            get_elapsed(result.elapsed)
+        ),
+        Err(e) => println!("Solution to part {} {}", result.part, e),
+    }
+}
+
+pub fn print_table(results: &[(usize, DayResult)]) {
+    let header = ["Day", "Part", "Answer", "Time"];
+    let rows = results
+        .iter()
+        .flat_map(|(day, result)| {
+            let parse = vec![
+                format!("{:02}", day),
+                "input".to_owned(),
+                String::new(),
+                get_elapsed(result.parse_elapsed),
+            ];
+            let parts = result.parts.iter().map(move |part| {
+                vec![
+                    format!("{:02}", day),
+                    part.part.to_string(),
+                    match &part.answer {
+                        Ok(res) => res.to_owned(),
+                        Err(e) => e.to_string(),
+                    },
+                    get_elapsed(part.elapsed),
+                ]
+            });
+            std::iter::once(parse).chain(parts)
+        })
+        .collect_vec();
+
+    print_grid(&header, &rows, 3);
+
+    let (parse, solve) = get_totals(results);
+    println!(
+        "Total: {} (parse {}, solve {}, {} parts)",
+        get_elapsed(parse + solve),
+        get_elapsed(parse),
+        get_elapsed(solve),
+        results
+            .iter()
+            .map(|(_, result)| result.parts.len())
+            .sum::<usize>()
+    );
+}
+
+/// Prints an aligned table. Columns from `left_columns` onwards are right-aligned.
+pub fn print_grid(header: &[&str], rows: &[Vec<String>], left_columns: usize) {
+    let mut widths = header.iter().map(|v| v.chars().count()).collect_vec();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &mut dyn Iterator<Item = &str>| {
+        let line = row
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i < left_columns {
+                    format!("{:<width$}", cell)
+                } else {
+                    format!("{:>width$}", cell)
+                }
+            })
+            .join(" | ");
+        println!("{}", line.trim_end());
+    };
+    let separator = widths.iter().map(|w| "-".repeat(*w)).join("-+-");
+
+    print_row(&mut header.iter().copied());
+    println!("{separator}");
+    for row in rows {
+        print_row(&mut row.iter().map(|v| v.as_str()));
+    }
+    println!("{separator}");
+}
+
+/// Prints one record per part, for `Format::Json` or `Format::Csv`, followed by
+/// the aggregated parse and solve times.
+pub fn print_records(format: Format, results: &[(usize, DayResult)]) {
+    print!("{}", format_records(format, results));
+}
+
+fn format_records(format: Format, results: &[(usize, DayResult)]) -> String {
+    let mut output = String::new();
+    let (parse, solve) = get_totals(results);
+    let records = results
+        .iter()
+        .flat_map(|(day, result)| result.parts.iter().map(move |part| (*day, result, part)));
+
+    match format {
+        Format::Json => {
+            let records = records
+                .map(|(day, result, part)| {
+                    format!(
+                        "    {{\"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \"error\": {}, \"parse_time_ns\": {}, \"solve_time_ns\": {}}}",
+                        day,
+                        part.part,
+                        status(part),
+                        json_string(part.answer.as_ref().ok()),
+                        json_string(part.answer.as_ref().err().and_then(|e| e.message())),
+                        result.parse_elapsed.as_nanos(),
+                        part.elapsed.as_nanos()
+                    )
+                })
+                .join(",\n");
+            output += "{\n";
+            if records.is_empty() {
+                output += "  \"parts\": [],\n";
+            } else {
+                output += &format!("  \"parts\": [\n{records}\n  ],\n");
+            }
+            output += &format!("  \"parse_time_ns\": {},\n", parse.as_nanos());
+            output += &format!("  \"solve_time_ns\": {},\n", solve.as_nanos());
+            output += &format!("  \"total_time_ns\": {}\n", (parse + solve).as_nanos());
+            output += "}\n";
+        }
+        Format::Csv => {
+            output += "day,part,status,answer,error,parse_time_ns,solve_time_ns\n";
+            for (day, result, part) in records {
+                output += &format!(
+                    "{},{},{},{},{},{},{}\n",
+                    day,
+                    part.part,
+                    status(part),
+                    csv_field(part.answer.as_ref().ok()),
+                    csv_field(part.answer.as_ref().err().and_then(|e| e.message())),
+                    result.parse_elapsed.as_nanos(),
+                    part.elapsed.as_nanos()
+                );
+            }
+            output += &format!("total,,,,,{},{}\n", parse.as_nanos(), solve.as_nanos());
+        }
+        Format::Text => unreachable!("text output is printed by print_day or print_table"),
+    }
+    output
+}
+
+fn status(part: &PartResult) -> &'static str {
+    match &part.answer {
+        Ok(_) => "ok",
+        Err(e) => e.status(),
+    }
+}
+
+/// Total parse and solve time, counting each day's parse once.
+fn get_totals(results: &[(usize, DayResult)]) -> (Duration, Duration) {
+    results.iter().fold(
+        (Duration::ZERO, Duration::ZERO),
+        |(parse, solve), (_, result)| {
+            (parse + result.parse_elapsed, solve + result.solve_elapsed())
+        },
+    )
+}
+
+fn json_string(value: Option<&String>) -> String {
+    let Some(value) = value else {
+        return "null".to_owned();
+    };
+
+    let mut result = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn csv_field(value: Option<&String>) -> String {
+    match value {
+        Some(value) if value.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value.to_owned(),
+        None => String::new(),
+    }
+}
+
+/// Like `get_elapsed`, but keeping three decimals for averaged measurements.
+pub fn get_elapsed_precise(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{:.3} ns", n),
+        n if n < 1e6 => format!("{:.3} μs", n / 1e3),
+        n if n < 1e9 => format!("{:.3} ms", n / 1e6),
+        n => format!("{:.3} s", n / 1e9),
+    }
+}
+
+fn get_elapsed(elapsed: Duration) -> String {
+    let nanos = elapsed.as_nanos();
+    let decimals = format!("{nanos}").len();
+    match decimals {
+        0..=4 => format!("{} ns", elapsed.as_nanos()),
+        5..=7 => format!("{} μs", elapsed.as_micros()),
+        8..=10 => format!("{} ms", elapsed.as_millis()),
+        _ => format!("{} s", elapsed.as_secs()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::{ParseError, PartError, Parts};
+
+    fn day(answers: Vec<Result<String, PartError>>) -> DayResult {
+        DayResult {
+            parse_elapsed: Duration::from_nanos(100),
+            parts: answers
+                .into_iter()
+                .enumerate()
+                .map(|(i, answer)| PartResult {
+                    part: i + 1,
+                    answer,
+                    elapsed: Duration::from_nanos(10),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string(None), "null");
+        assert_eq!(json_string(Some(&"42".to_owned())), "\"42\"");
+        assert_eq!(
+            json_string(Some(&"a \"b\"\\\n\tc\u{1}".to_owned())),
+            r#""a \"b\"\\\n\tc\u0001""#
+        );
+    }
+
+    #[test]
+    fn escapes_csv_fields() {
+        assert_eq!(csv_field(None), "");
+        assert_eq!(csv_field(Some(&"42".to_owned())), "42");
+        assert_eq!(csv_field(Some(&"a,b".to_owned())), "\"a,b\"");
+        assert_eq!(
+            csv_field(Some(&"say \"hi\"\n".to_owned())),
+            "\"say \"\"hi\"\"\n\""
+        );
+    }
+
+    #[test]
+    fn formats_days_without_input() {
+        let error = ParseError::new("can't read input").in_file("inputs/2023/02".as_ref());
+        let results = [
+            (
+                1,
+                day(vec![
+                    Ok("7".to_owned()),
+                    Err(PartError::Failed("no, \"x\"".to_owned())),
+                ]),
+            ),
+            (2, DayResult::unparsed(&error, Parts::Both)),
+        ];
+
+        assert_eq!(
+            format_records(Format::Csv, &results),
+            "day,part,status,answer,error,parse_time_ns,solve_time_ns\n\
+             1,1,ok,7,,100,10\n\
+             1,2,failed,,\"no, \"\"x\"\"\",100,10\n\
+             2,1,parse-error,,inputs/2023/02: can't read input,0,0\n\
+             2,2,parse-error,,inputs/2023/02: can't read input,0,0\n\
+             total,,,,,100,20\n"
+        );
+
+        let json = format_records(Format::Json, &results[1..]);
+        assert!(
+            json.starts_with(
+                "{\n  \"parts\": [\n    \
+                 {\"day\": 2, \"part\": 1, \"status\": \"parse-error\", \"answer\": null, \
+                 \"error\": \"inputs/2023/02: can't read input\", \
+                 \"parse_time_ns\": 0, \"solve_time_ns\": 0},\n"
+            ),
+            "{json}"
+        );
+    }
+}
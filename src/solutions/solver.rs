@@ -1,20 +1,126 @@
+use super::params::Params;
+use super::parse::ParseError;
+use clap::ValueEnum;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
-use std::fs::File;
-use std::io::BufReader;
-use std::time::Instant;
+use std::io::BufRead;
+use std::panic::AssertUnwindSafe;
+use std::sync::Once;
+use std::time::{Duration, Instant};
 
-macro_rules! printResult {
-    ($part:expr, $result:expr, $start:expr ) => {
-        match $result {
-            Ok(res) => println!(
-                "Solution to part {}: {} ({})",
-                $part,
-                res,
-                get_elapsed($start)
-            ),
-            Err(val) => println!("Solution to part {} errored: {}", $part, val),
+/// Which parts of a day to solve.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum Parts {
+    #[value(name = "1")]
+    First,
+    #[value(name = "2")]
+    Second,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    /// Just the given part, 1 or 2.
+    pub fn only(part: usize) -> Parts {
+        match part {
+            1 => Parts::First,
+            2 => Parts::Second,
+            _ => panic!("unknown part {part}"),
+        }
+    }
+
+    /// The part numbers, in order.
+    pub fn numbers(self) -> impl Iterator<Item = usize> {
+        let numbers: &[usize] = match self {
+            Parts::First => &[1],
+            Parts::Second => &[2],
+            Parts::Both => &[1, 2],
+        };
+        numbers.iter().copied()
+    }
+}
+
+/// Why a part has no answer.
+#[derive(Debug, PartialEq)]
+pub enum PartError {
+    /// The solver returned an error
+    Failed(String),
+    /// The solver panicked, with the message and location
+    Panicked(String),
+    /// The solver is still `todo!()` or `unimplemented!()`
+    Unimplemented,
+    /// The day's input couldn't be read or parsed
+    Unparsed(String),
+}
+
+impl PartError {
+    /// Short name for machine readable output.
+    pub fn status(&self) -> &'static str {
+        match self {
+            PartError::Failed(_) => "failed",
+            PartError::Panicked(_) => "panicked",
+            PartError::Unimplemented => "unimplemented",
+            PartError::Unparsed(_) => "parse-error",
+        }
+    }
+
+    pub fn message(&self) -> Option<&String> {
+        match self {
+            PartError::Failed(message)
+            | PartError::Panicked(message)
+            | PartError::Unparsed(message) => Some(message),
+            PartError::Unimplemented => None,
+        }
+    }
+}
+
+impl Display for PartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartError::Failed(e) => write!(f, "errored: {e}"),
+            PartError::Panicked(e) => write!(f, "panicked: {e}"),
+            PartError::Unimplemented => write!(f, "not implemented"),
+            PartError::Unparsed(e) => write!(f, "has no input: {e}"),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: usize,
+    pub answer: Result<String, PartError>,
+    pub elapsed: Duration,
+}
+
+pub struct DayResult {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    /// A day whose input couldn't be read or parsed, with the error as the
+    /// answer of every part, so it still shows up in the output.
+    pub fn unparsed(error: &ParseError, parts: Parts) -> Self {
+        DayResult {
+            parse_elapsed: Duration::ZERO,
+            parts: parts
+                .numbers()
+                .map(|part| PartResult {
+                    part,
+                    answer: Err(PartError::Unparsed(error.to_string())),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
         }
-    };
+    }
+
+    pub fn solve_elapsed(&self) -> Duration {
+        self.parts.iter().map(|part| part.elapsed).sum()
+    }
+
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.solve_elapsed()
+    }
 }
 
 pub trait Solver {
@@ -22,33 +128,231 @@ pub trait Solver {
     type Output1: Display;
     type Output2: Display;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input;
+    /// Keys accepted with `--param`, read in [`Solver::read_input_with`].
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError>;
+
+    /// Parses the input along with the `--param` values, for solvers that
+    /// declare [`Solver::PARAMS`].
+    fn read_input_with(
+        &self,
+        reader: impl BufRead,
+        _params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        self.read_input(reader)
+    }
+
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String>;
     fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String>;
+}
+
+/// Input parsed by an [`ErasedSolver`], only usable by the solver that parsed it.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// Object-safe view of a [`Solver`], so days with different input and output
+/// types can live in the same registry. Answers are rendered to strings.
+pub trait ErasedSolver {
+    fn params(&self) -> &'static [&'static str];
+    fn parse(
+        &self,
+        reader: &mut dyn BufRead,
+        params: &Params,
+    ) -> Result<(ParsedInput, Duration), ParseError>;
+    fn solve_part(&self, input: &ParsedInput, part: usize) -> PartResult;
+
+    fn run(
+        &self,
+        reader: &mut dyn BufRead,
+        parts: Parts,
+        params: &Params,
+    ) -> Result<DayResult, ParseError> {
+        let (input, parse_elapsed) = self.parse(reader, params)?;
+        Ok(DayResult {
+            parse_elapsed,
+            parts: parts
+                .numbers()
+                .map(|part| self.solve_part(&input, part))
+                .collect(),
+        })
+    }
+
+    /// Runs an in-memory input, such as a file's contents or a puzzle example.
+    fn solve_str(
+        &self,
+        input: &str,
+        parts: Parts,
+        params: &Params,
+    ) -> Result<DayResult, ParseError> {
+        self.run(&mut input.as_bytes(), parts, params)
+    }
+}
+
+impl<S> ErasedSolver for S
+where
+    S: Solver,
+    S::Input: 'static,
+{
+    fn params(&self) -> &'static [&'static str] {
+        S::PARAMS
+    }
+
+    fn parse(
+        &self,
+        reader: &mut dyn BufRead,
+        params: &Params,
+    ) -> Result<(ParsedInput, Duration), ParseError> {
+        let (input, elapsed) = timed(|| catch_panic(|| Ok(self.read_input_with(reader, params))));
+        let input = input.map_err(|e| ParseError::new(format!("reading the input {e}")))??;
+        Ok((ParsedInput(Box::new(input)), elapsed))
+    }
 
-    fn solve(&self, filename: String, parts: usize) {
-        let file = File::open(filename).expect("input file not found");
-        let input = self.read_input(BufReader::new(&file));
-        if parts & 0x1 > 0 {
-            let start = Instant::now();
-            printResult!(1, self.solve_first(&input), start);
+    fn solve_part(&self, input: &ParsedInput, part: usize) -> PartResult {
+        let input = input
+            .0
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solver");
+        let (answer, elapsed) = match part {
+            1 => {
+                let (answer, elapsed) = timed(|| catch_panic(|| self.solve_first(input)));
+                (answer.map(|v| v.to_string()), elapsed)
+            }
+            2 => {
+                let (answer, elapsed) = timed(|| catch_panic(|| self.solve_second(input)));
+                (answer.map(|v| v.to_string()), elapsed)
+            }
+            _ => panic!("unknown part {part}"),
+        };
+        PartResult {
+            part,
+            answer,
+            elapsed,
         }
-        if parts & 0x2 > 0 {
-            let start = Instant::now();
-            printResult!(2, self.solve_second(&input), start)
+    }
+}
+
+thread_local! {
+    /// Set while a part runs, so its panics are returned instead of printed
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs a part or the input parsing, turning its panics into a [`PartError`]
+/// so the other parts and days still run.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, PartError> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(|catching| catching.get()) {
+                let location = info.location().map(|l| l.to_string());
+                PANIC_LOCATION.with(|l| *l.borrow_mut() = location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    CATCHING.with(|catching| catching.set(true));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(false));
+
+    match result {
+        Ok(answer) => answer.map_err(PartError::Failed),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|m| m.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            if message.starts_with("not yet implemented") || message.starts_with("not implemented")
+            {
+                return Err(PartError::Unimplemented);
+            }
+            match PANIC_LOCATION.with(|l| l.borrow_mut().take()) {
+                Some(location) => Err(PartError::Panicked(format!("{message} at {location}"))),
+                None => Err(PartError::Panicked(message)),
+            }
         }
     }
 }
 
-fn get_elapsed(start: Instant) -> String {
-    let elapsed = start.elapsed();
+/// Runs `f`, returning its result and how long it took.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Unfinished;
+
+    impl Solver for Unfinished {
+        type Input = Vec<usize>;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn read_input(&self, _reader: impl BufRead) -> Result<Self::Input, ParseError> {
+            Ok(Vec::new())
+        }
+
+        fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+            Ok(input[0])
+        }
+
+        fn solve_second(&self, _input: &Self::Input) -> Result<Self::Output2, String> {
+            todo!()
+        }
+    }
+
+    struct Unparsable;
+
+    impl Solver for Unparsable {
+        type Input = usize;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn read_input(&self, _reader: impl BufRead) -> Result<Self::Input, ParseError> {
+            let numbers: Vec<usize> = Vec::new();
+            Ok(numbers[0])
+        }
+
+        fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+            Ok(*input)
+        }
+
+        fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+            Ok(*input)
+        }
+    }
+
+    #[test]
+    fn catches_panicking_parsers() {
+        let Err(e) = Unparsable.solve_str("", Parts::Both, &Params::default()) else {
+            panic!("parsing should have failed");
+        };
+        assert!(
+            e.message
+                .starts_with("reading the input panicked: index out of bounds"),
+            "{}",
+            e.message
+        );
+    }
+
+    #[test]
+    fn catches_panicking_parts() {
+        let result = Unfinished
+            .solve_str("", Parts::Both, &Params::default())
+            .unwrap();
 
-    let nanos = elapsed.as_nanos();
-    let decimals = format!("{nanos}").len();
-    match decimals {
-        0..=4 => format!("{} ns", elapsed.as_nanos()),
-        5..=7 => format!("{} μs", elapsed.as_micros()),
-        8..=10 => format!("{} ms", elapsed.as_millis()),
-        _ => format!("{} s", elapsed.as_secs()),
+        let Err(PartError::Panicked(message)) = &result.parts[0].answer else {
+            panic!("part 1 should have panicked");
+        };
+        assert!(message.starts_with("index out of bounds"), "{message}");
+        assert!(message.contains("src/solutions/solver.rs"), "{message}");
+        assert_eq!(result.parts[1].answer, Err(PartError::Unimplemented));
     }
 }
@@ -0,0 +1,57 @@
+use crate::solutions::parse::{read_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
+
+pub struct Problem;
+
+/// Lines between two blank lines.
+#[derive(Default)]
+pub struct Block {
+    lines: Vec<String>,
+}
+
+impl Solver for Problem {
+    type Input = Vec<Block>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut result = Vec::new();
+        let mut current = Block::default();
+
+        for line in read_lines(reader)? {
+            if line.is_empty() {
+                if !current.lines.is_empty() {
+                    result.push(std::mem::take(&mut current));
+                }
+            } else {
+                current.lines.push(line);
+            }
+        }
+        if !current.lines.is_empty() {
+            result.push(current);
+        }
+
+        Ok(result)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    examples! {
+        example: EXAMPLE => (_, _),
+    }
+}
@@ -0,0 +1,58 @@
+use crate::solutions::parse::{map_lines, read_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
+
+pub struct Problem;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Wall),
+            _ => Err(format!("Unknown tile {value}")),
+        }
+    }
+}
+
+impl Solver for Problem {
+    type Input = Vec<Vec<Tile>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        map_lines(&read_lines(reader)?, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(i, c)| c.try_into().map_err(|e| ParseError::at_column(i, e)))
+                .collect()
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    examples! {
+        example: EXAMPLE => (_, _),
+    }
+}
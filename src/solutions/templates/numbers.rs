@@ -0,0 +1,35 @@
+use crate::solutions::parse::{parse_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
+
+pub struct Problem;
+
+impl Solver for Problem {
+    type Input = Vec<isize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(reader)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    examples! {
+        example: EXAMPLE => (_, _),
+    }
+}
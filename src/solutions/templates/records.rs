@@ -0,0 +1,51 @@
+use crate::solutions::parse::{parse_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub struct Problem;
+
+#[derive(Debug)]
+pub struct Record {
+    fields: Vec<String>,
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Record {
+            fields: s.split_whitespace().map(|v| v.to_owned()).collect(),
+        })
+    }
+}
+
+impl Solver for Problem {
+    type Input = Vec<Record>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(reader)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    examples! {
+        example: EXAMPLE => (_, _),
+    }
+}
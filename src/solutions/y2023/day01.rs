@@ -1,6 +1,6 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::parse::{read_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -9,8 +9,8 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader.lines().map(|x| x.unwrap()).collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_lines(reader)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -98,3 +98,30 @@ fn get_trie_digit(input: &str, trie: &mut Trie) -> usize {
         })
         .unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    examples! {
+        example_1: EXAMPLE_1 => (142, _),
+        example_2: EXAMPLE_2 => (_, 281),
+    }
+}
@@ -1,8 +1,8 @@
 use itertools::Itertools;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::parse::{parse_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
 use std::str::FromStr;
 
 pub struct Problem;
@@ -64,13 +64,13 @@ impl FromStr for Game {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, draws) = s.split(": ").collect_tuple().unwrap();
+        let (_, draws) = s
+            .split(": ")
+            .collect_tuple()
+            .ok_or("Unknown game format")?;
 
         Ok(Game {
-            sets: draws
-                .split("; ")
-                .map(|str| Draw::from_str(str).unwrap())
-                .collect(),
+            sets: draws.split("; ").map(Draw::from_str).collect::<Result<_, _>>()?,
         })
     }
 }
@@ -80,13 +80,8 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(reader)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -116,3 +111,20 @@ impl Solver for Problem {
             .sum())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    examples! {
+        example: EXAMPLE => (8, 2286),
+    }
+}
@@ -1,8 +1,8 @@
 use itertools::Itertools;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::parse::{read_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
 use std::ops::Range;
 
 pub struct Problem;
@@ -12,8 +12,8 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader.lines().map(|x| x.unwrap()).collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_lines(reader)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -176,3 +176,25 @@ fn cap_col_range(grid: &Vec<String>, range: Range<isize>) -> Range<usize> {
 fn cap_col(grid: &Vec<String>, col: isize) -> usize {
     (col.max(0) as usize).min(grid[0].len() - 1)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    examples! {
+        example: EXAMPLE => (4361, 467835),
+    }
+}
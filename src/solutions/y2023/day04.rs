@@ -1,9 +1,9 @@
 use itertools::Itertools;
 
-use super::Solver;
+use crate::solutions::parse::{parse_lines, ParseError};
+use crate::solutions::Solver;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::str::FromStr;
 
 pub struct Problem;
@@ -27,11 +27,13 @@ impl FromStr for Card {
             .collect_tuple()
             .ok_or("Unknown card fromat")?;
 
-        let winning = winning_list
-            .split(" ")
-            .map(|x| x.parse().unwrap())
-            .collect();
-        let owning = owning_list.split(" ").map(|x| x.parse().unwrap()).collect();
+        let parse_list = |list: &str| {
+            list.split(" ")
+                .map(|x| x.parse().map_err(|_| format!("Invalid number {x}")))
+                .collect::<Result<_, _>>()
+        };
+        let winning = parse_list(winning_list)?;
+        let owning = parse_list(owning_list)?;
 
         Ok(Card { winning, owning })
     }
@@ -61,13 +63,8 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = u64;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(reader)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -87,3 +84,21 @@ impl Solver for Problem {
         Ok(quantities.iter().sum())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    examples! {
+        example: EXAMPLE => (13, 30),
+    }
+}
@@ -1,7 +1,7 @@
-use super::Solver;
+use crate::solutions::parse::{read_lines, ParseError};
+use crate::solutions::Solver;
 use itertools::Itertools;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::ops::Range;
 use std::str::FromStr;
 
@@ -23,10 +23,12 @@ impl FromStr for Mapping {
             .collect_tuple()
             .ok_or("Unkown mapping format")?;
 
+        let parse = |v: &str| v.parse().map_err(|_| format!("Invalid number {v}"));
+
         return Ok(Mapping {
-            source: source.parse().unwrap(),
-            destination: destination.parse().unwrap(),
-            range: range.parse().unwrap(),
+            source: parse(source)?,
+            destination: parse(destination)?,
+            range: parse(range)?,
         });
     }
 }
@@ -132,14 +134,29 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(reader)?;
 
-        let (_, seed_list) = lines[0].split(": ").collect_tuple().unwrap();
-        let seeds = seed_list
-            .split(" ")
-            .map(|x| x.parse().unwrap())
-            .collect_vec();
+        let seeds_line = lines.first().ok_or("Empty almanac")?;
+        let seeds = seeds_line
+            .split(": ")
+            .collect_tuple()
+            .map(|(_, seed_list)| seed_list)
+            .ok_or(ParseError::new("Unknown seeds format"))
+            .and_then(|seed_list| {
+                seed_list
+                    .split(" ")
+                    .map(|x| x.parse::<usize>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(ParseError::from)
+            })
+            .map_err(|e| e.on_line(0, seeds_line))?;
+        if seeds.len() % 2 != 0 {
+            return Err(
+                ParseError::new("Seeds should come in start and length pairs")
+                    .on_line(0, seeds_line),
+            );
+        }
         let seed_ranges = seeds.chunks(2).map(|v| v[0]..(v[0] + v[1])).collect_vec();
 
         let mut mappings = Vec::new();
@@ -151,18 +168,22 @@ impl Solver for Problem {
                 });
                 current_mapping = Vec::new();
             } else if lines[i] != "" {
-                current_mapping.push(lines[i].parse().unwrap());
+                current_mapping.push(
+                    lines[i]
+                        .parse()
+                        .map_err(|e: String| ParseError::from(e).on_line(i, &lines[i]))?,
+                );
             }
         }
         mappings.push(Mappings {
             mappings: current_mapping,
         });
 
-        Almanac {
+        Ok(Almanac {
             seeds,
             mappings,
             seed_ranges,
-        }
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -209,3 +230,57 @@ fn merge_ranges(ranges: &Vec<Range<usize>>) -> Vec<Range<usize>> {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    examples! {
+        example: EXAMPLE => (35, 46),
+    }
+
+    #[test]
+    fn rejects_unpaired_seeds() {
+        let Err(e) = Problem.read_input("seeds: 1 2 3\n".as_bytes()) else {
+            panic!("odd seed counts should be rejected");
+        };
+        assert_eq!(e.line, Some(1));
+        assert_eq!(e.message, "Seeds should come in start and length pairs");
+    }
+}
@@ -0,0 +1,103 @@
+use crate::solutions::parse::{map_lines, read_lines, ParseError};
+use crate::solutions::Solver;
+use itertools::Itertools;
+use std::io::BufRead;
+use std::iter::zip;
+use std::ops::Range;
+
+pub struct Problem;
+
+pub struct Race {
+    time: usize,
+    record: usize,
+}
+
+impl Race {
+    pub fn get_winning_range(self: &Self) -> Range<usize> {
+        let a = -1 as f64;
+        let b = self.time as f64;
+        let c = -(self.record as f64);
+
+        let b2_4ac = b * b - 4. * a * c;
+        let p_min = (-b + b2_4ac.sqrt()) / (2. * a);
+        let p_max = (-b - b2_4ac.sqrt()) / (2. * a);
+
+        let p_min = (p_min + 1.).floor() as usize;
+        let p_max = (p_max - 1.).ceil() as usize;
+
+        return p_min..(p_max + 1);
+    }
+}
+
+impl Solver for Problem {
+    type Input = (Vec<Race>, Race);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(reader)?;
+        if lines.len() < 2 {
+            return Err("Expected a time line and a distance line".into());
+        }
+        let ((times, time), (distances, record)) =
+            map_lines(&lines[..2], |line| -> Result<_, String> {
+                Ok((read_line(line)?, read_line_no_space(line)?))
+            })?
+            .into_iter()
+            .collect_tuple()
+            .unwrap();
+
+        let races = zip(times, distances)
+            .map(|(time, record)| Race { time, record })
+            .collect();
+
+        let race = Race { time, record };
+
+        Ok((races, race))
+    }
+
+    fn solve_first(&self, (input, _): &Self::Input) -> Result<Self::Output1, String> {
+        Ok(input.iter().map(|x| x.get_winning_range().len()).product())
+    }
+
+    fn solve_second(&self, (_, input): &Self::Input) -> Result<Self::Output2, String> {
+        Ok(input.get_winning_range().len())
+    }
+}
+
+fn read_line(line: &str) -> Result<Vec<usize>, String> {
+    let (_, numbers) = line
+        .split(":")
+        .collect_tuple()
+        .ok_or("Unknown line format")?;
+
+    numbers
+        .split(" ")
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().map_err(|_| format!("Invalid number {v}")))
+        .collect()
+}
+
+fn read_line_no_space(line: &str) -> Result<usize, String> {
+    let (_, numbers) = line
+        .split(":")
+        .collect_tuple()
+        .ok_or("Unknown line format")?;
+
+    let number = numbers.replace(" ", "");
+    number.parse().map_err(|_| format!("Invalid number {number}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    examples! {
+        example: EXAMPLE => (288, 71503),
+    }
+}
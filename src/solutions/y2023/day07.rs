@@ -1,15 +1,17 @@
 use itertools::Itertools;
 
-use super::Solver;
+use crate::solutions::parse::{parse_lines, ParseError};
+use crate::solutions::Solver;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::iter::zip;
 use std::str::FromStr;
 
 pub struct Problem;
 
+const HAND_SIZE: usize = 5;
+
 #[derive(Clone, Debug)]
 pub struct Hand {
     cards: Vec<u32>,
@@ -87,21 +89,33 @@ impl Hand {
 }
 
 impl FromStr for Hand {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (card_list, bid) = s.split(" ").collect_tuple().ok_or("Unknown hand format")?;
 
+        let cards = card_list
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                '2'..='9' => Ok(c.to_digit(10).unwrap()),
+                'T' => Ok(10),
+                'J' => Ok(1), // TODO 11 instead of 1 for part 1
+                'Q' => Ok(12),
+                'K' => Ok(13),
+                'A' => Ok(14),
+                _ => Err(ParseError::at_column(i, format!("Unknown card {c}"))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if cards.len() != HAND_SIZE {
+            return Err(ParseError::at_column(
+                cards.len().min(HAND_SIZE),
+                format!("Hands have {HAND_SIZE} cards, got {}", cards.len()),
+            ));
+        }
+
         Ok(Hand::new(
-            card_list
-                .replace("A", "E")
-                .replace("T", "A")
-                .replace("J", "1") // TODO "B" instead of "1" for part 1
-                .replace("Q", "C")
-                .replace("K", "D")
-                .chars()
-                .map(|c| c.to_digit(16).unwrap())
-                .collect(),
+            cards,
             bid.parse().map_err(|_| "Can't parse bid")?,
         ))
     }
@@ -112,13 +126,8 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(reader)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -152,3 +161,35 @@ impl Solver for Problem {
         self.solve_first(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    examples! {
+        // Part 1 shares the joker rules of part 2, see `Hand::from_str`
+        example: EXAMPLE => (_, 5905),
+    }
+
+    #[test]
+    fn rejects_invalid_hands() {
+        let e = Problem
+            .read_input("32T3K 765\nFFFFB 5\n".as_bytes())
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (Some(2), Some(1)));
+        assert_eq!(e.message, "Unknown card F");
+
+        let e = Problem.read_input("333333 5\n".as_bytes()).unwrap_err();
+        assert_eq!((e.line, e.column), (Some(1), Some(6)));
+        assert_eq!(e.message, "Hands have 5 cards, got 6");
+        assert_eq!(e.text.as_deref(), Some("333333 5"));
+    }
+}
@@ -1,10 +1,10 @@
 use itertools::Itertools;
 use num::Integer;
 
-use super::Solver;
+use crate::solutions::parse::{read_lines, ParseError};
+use crate::solutions::Solver;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -23,38 +23,31 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(reader)?;
 
-        let instructions = lines[0]
+        let instructions_line = lines.first().ok_or("Empty map")?;
+        let instructions = instructions_line
             .chars()
-            .map(|c| {
-                if c == 'R' {
-                    Instruction::Right
-                } else {
-                    Instruction::Left
-                }
+            .enumerate()
+            .map(|(i, c)| match c {
+                'R' => Ok(Instruction::Right),
+                'L' => Ok(Instruction::Left),
+                _ => Err(ParseError::at_column(i, format!("Unknown instruction {c}"))
+                    .on_line(0, instructions_line)),
             })
-            .collect();
-        let nodes = lines[2..].iter().fold(HashMap::default(), |mut acc, line| {
-            let (source, routes) = line.split(" = ").collect_tuple().unwrap();
-            let (left, right) = routes.split(", ").collect_tuple().unwrap();
-
-            acc.insert(
-                source.to_owned(),
-                (
-                    String::from(&left[1..]),
-                    String::from(&right[..right.len() - 1]),
-                ),
-            );
-
-            acc
-        });
-
-        Map {
+            .collect::<Result<_, _>>()?;
+        let nodes = lines
+            .iter()
+            .enumerate()
+            .skip(2)
+            .map(|(i, line)| read_node(line).map_err(|e| ParseError::from(e).on_line(i, line)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map {
             instructions,
             nodes,
-        }
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -88,6 +81,20 @@ impl Solver for Problem {
     }
 }
 
+fn read_node(line: &str) -> Result<(String, (String, String)), String> {
+    let (source, routes) = line
+        .split(" = ")
+        .collect_tuple()
+        .ok_or("Unknown node format")?;
+    let (left, right) = routes
+        .strip_prefix('(')
+        .and_then(|routes| routes.strip_suffix(')'))
+        .and_then(|routes| routes.split(", ").collect_tuple())
+        .ok_or("Unknown routes format")?;
+
+    Ok((source.to_owned(), (left.to_owned(), right.to_owned())))
+}
+
 fn find_loop(input: &Map, start: &str) -> usize {
     let mut visited: HashSet<(usize, &str)> = HashSet::new();
     let mut position = start;
@@ -111,3 +118,47 @@ fn find_loop(input: &Map, start: &str) -> usize {
 
     exits.into_iter().reduce(|a, b| a.lcm(&b)).unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    examples! {
+        example_1: EXAMPLE_1 => (2, _),
+        example_2: EXAMPLE_2 => (6, _),
+        example_3: EXAMPLE_3 => (_, 6),
+    }
+}
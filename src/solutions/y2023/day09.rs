@@ -1,6 +1,6 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::parse::{map_lines, read_lines, ParseError};
+use crate::solutions::Solver;
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -9,12 +9,10 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.split(" ").map(|v| v.parse().unwrap()).collect())
-            .collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        map_lines(&read_lines(reader)?, |line| {
+            line.split(" ").map(|v| v.parse()).collect()
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -59,3 +57,18 @@ fn find_prev_value(input: &Vec<isize>) -> isize {
         input[0] - value
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    examples! {
+        example: EXAMPLE => (114, 2),
+    }
+}
@@ -1,9 +1,9 @@
 use itertools::Itertools;
 
-use super::Solver;
+use crate::solutions::parse::{map_lines, read_lines, ParseError};
+use crate::solutions::Solver;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -32,7 +32,7 @@ impl TryFrom<char> for Pipe {
             'F' => Ok(Pipe::SE),
             '.' => Ok(Pipe::G),
             'S' => Ok(Pipe::S),
-            _ => Err("Unknown pipe".to_owned()),
+            _ => Err(format!("Unknown pipe {value}")),
         }
     }
 }
@@ -42,12 +42,13 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.chars().map(|c| c.try_into().unwrap()).collect())
-            .collect()
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        map_lines(&read_lines(reader)?, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(i, c)| c.try_into().map_err(|e| ParseError::at_column(i, e)))
+                .collect()
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -111,7 +112,7 @@ impl Solver for Problem {
             .filter(|(x, y)| input[*y][*x] == Pipe::G)
             .collect_vec();
 
-        // < 580, != 63, != 93
+        // < 580, != 63, != 93, see attempts/2023/10
         Ok(grounds.len())
     }
 }
@@ -339,3 +340,30 @@ fn get_adjacent_ground(pipes: &Vec<Vec<Pipe>>, (x, y): (usize, usize)) -> Vec<(u
 fn is_edge(pipes: &Vec<Vec<Pipe>>, (x, y): (usize, usize)) -> bool {
     x == 0 || x == pipes[0].len() - 1 || y == 0 || y == pipes.len() - 1
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+    const EXAMPLE_2: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    examples! {
+        example_1: EXAMPLE_1 => (4, _),
+        example_2: EXAMPLE_2 => (8, _),
+        // Part 2 doesn't solve the puzzle examples yet, see `solve_second`
+    }
+}
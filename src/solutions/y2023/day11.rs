@@ -1,8 +1,8 @@
-use super::Solver;
+use crate::solutions::parse::{map_lines, read_lines, ParseError};
+use crate::solutions::Solver;
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
 pub struct Problem;
 
@@ -56,25 +56,28 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(reader)?;
 
-        let galaxies = lines
-            .iter()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter(|(_, v)| *v == '#')
-                    .map(|(x, _)| (x, y))
-                    .collect_vec()
-            })
-            .collect();
+        let galaxies = map_lines(&lines, |line| {
+            line.chars()
+                .enumerate()
+                .filter_map(|(x, v)| match v {
+                    '#' => Some(Ok(x)),
+                    '.' => None,
+                    _ => Some(Err(ParseError::at_column(x, format!("Unknown space {v}")))),
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })?
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, xs)| xs.into_iter().map(move |x| (x, y)))
+        .collect_vec();
 
-        Space {
+        Ok(Space {
             galaxies,
-            range: (lines[0].len(), lines.len()),
-        }
+            range: (lines.first().map_or(0, |line| line.len()), lines.len()),
+        })
     }
 
     fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
@@ -89,3 +92,25 @@ impl Solver for Problem {
         Ok(expanded.get_distances_sum())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    examples! {
+        example: EXAMPLE => (374, 82000210),
+    }
+}
@@ -0,0 +1,279 @@
+use itertools::Itertools;
+use num::{CheckedAdd, One, Zero};
+use num_bigint::BigUint;
+
+use crate::solutions::parse::{parse_lines, ParseError};
+use crate::solutions::{Params, Solver};
+use std::fmt::Display;
+use std::io::BufRead;
+use std::ops::Add;
+use std::str::FromStr;
+
+pub struct Problem;
+
+/// Number of arrangements, which only becomes a `BigUint` once it no longer
+/// fits in a `usize`.
+#[derive(Debug, PartialEq)]
+pub enum Count {
+    Small(usize),
+    Big(BigUint),
+}
+
+impl Count {
+    fn into_big(self) -> BigUint {
+        match self {
+            Count::Small(v) => v.into(),
+            Count::Big(v) => v,
+        }
+    }
+}
+
+impl Add for Count {
+    type Output = Count;
+
+    fn add(self, other: Count) -> Count {
+        match (self, other) {
+            (Count::Small(a), Count::Small(b)) => match a.checked_add(b) {
+                Some(sum) => Count::Small(sum),
+                None => Count::Big(BigUint::from(a) + b),
+            },
+            (a, b) => Count::Big(a.into_big() + b.into_big()),
+        }
+    }
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Count::Small(v) => write!(f, "{v}"),
+            Count::Big(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    operational: Vec<Option<bool>>,
+    contiguous: Vec<usize>,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (operational, contiguous) = s.split(" ").collect_tuple().ok_or("Unkown format")?;
+
+        Ok(Report {
+            operational: operational
+                .chars()
+                .enumerate()
+                .map(|(i, v)| match v {
+                    '#' => Ok(Some(true)),
+                    '.' => Ok(Some(false)),
+                    '?' => Ok(None),
+                    _ => Err(ParseError::at_column(i, format!("Unknown spring {v}"))),
+                })
+                .collect::<Result<_, _>>()?,
+            contiguous: contiguous
+                .split(",")
+                .map(|v| v.parse())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+fn _print_posibility(p: &Vec<bool>) -> String {
+    p.iter()
+        .map(|v| match v {
+            true => '#',
+            false => '.',
+        })
+        .collect()
+}
+
+impl Report {
+    fn _to_string(self: &Self) -> String {
+        let operational: String = self
+            .operational
+            .iter()
+            .map(|v| match v {
+                Some(true) => '#',
+                Some(false) => '.',
+                None => '?',
+            })
+            .collect();
+        let contiguous = self.contiguous.iter().join(",");
+        format!("{operational} {contiguous}")
+    }
+
+    /// Counts the arrangements matching the known springs and the groups.
+    ///
+    /// `counts[p][g]` is the number of arrangements of the springs from `p` on
+    /// with the groups from `g` on, filled from the end of the row. `None` if a
+    /// count overflows `T`.
+    fn count_posibilities<T: Zero + One + CheckedAdd + Clone>(&self) -> Option<T> {
+        let springs = &self.operational;
+        let groups = &self.contiguous;
+        let (len, group_count) = (springs.len(), groups.len());
+
+        // Springs known to be operational before each position, to check that
+        // a group fits in O(1)
+        let mut dots = vec![0; len + 1];
+        for (i, spring) in springs.iter().enumerate() {
+            dots[i + 1] = dots[i] + usize::from(*spring == Some(false));
+        }
+
+        let mut counts = vec![vec![T::zero(); group_count + 1]; len + 2];
+        counts[len][group_count] = T::one();
+        counts[len + 1][group_count] = T::one();
+
+        for p in (0..len).rev() {
+            for g in 0..=group_count {
+                let mut count = T::zero();
+                if springs[p] != Some(true) {
+                    count = count.checked_add(&counts[p + 1][g])?;
+                }
+                if springs[p] != Some(false) && g < group_count {
+                    let end = p + groups[g];
+                    let fits = end <= len
+                        && dots[end] == dots[p]
+                        && (end == len || springs[end] != Some(true));
+                    if fits {
+                        // Skip the group and the operational spring after it
+                        count = count.checked_add(&counts[end + 1][g + 1])?;
+                    }
+                }
+                counts[p][g] = count;
+            }
+        }
+
+        Some(counts[0][0].clone())
+    }
+
+    /// Counts in a `usize`, redoing the count in a `BigUint` if it overflows.
+    fn count(&self) -> Count {
+        match self.count_posibilities() {
+            Some(count) => Count::Small(count),
+            None => Count::Big(self.count_posibilities().unwrap()),
+        }
+    }
+
+    fn unfold(&self, factor: usize) -> Self {
+        Report {
+            contiguous: (0..factor)
+                .flat_map(|_| self.contiguous.iter().map(|v| *v).collect_vec())
+                .collect(),
+            operational: (0..factor)
+                .flat_map(|i| {
+                    if i == factor - 1 {
+                        self.operational.iter().map(|v| v.to_owned()).collect_vec()
+                    } else {
+                        self.operational
+                            .iter()
+                            .chain(vec![None].iter())
+                            .map(|v| v.to_owned())
+                            .collect_vec()
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The reports, with how many times part 2 unfolds them.
+pub struct Records {
+    reports: Vec<Report>,
+    unfold: usize,
+}
+
+impl Solver for Problem {
+    type Input = Records;
+    type Output1 = Count;
+    type Output2 = Count;
+
+    const PARAMS: &'static [&'static str] = &["unfold"];
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        self.read_input_with(reader, &Params::default())
+    }
+
+    /// `unfold` is 5 unless given with `--param`.
+    fn read_input_with(
+        &self,
+        reader: impl BufRead,
+        params: &Params,
+    ) -> Result<Self::Input, ParseError> {
+        let unfold = params.get("unfold", 5)?;
+        if unfold == 0 {
+            return Err(ParseError::new("Can't unfold 0 times"));
+        }
+        Ok(Records {
+            reports: parse_lines(reader)?,
+            unfold,
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        Ok(input
+            .reports
+            .iter()
+            .map(|v| v.count())
+            .fold(Count::Small(0), Add::add))
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        Ok(input
+            .reports
+            .iter()
+            .map(|v| v.unfold(input.unfold).count())
+            .fold(Count::Small(0), Add::add))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    examples! {
+        example: EXAMPLE => (21, 525152),
+    }
+
+    #[test]
+    fn reads_the_unfold_factor() {
+        let params = Params::new(vec![("unfold".to_owned(), "1".to_owned())]);
+        let input = Problem
+            .read_input_with(EXAMPLE.as_bytes(), &params)
+            .unwrap();
+        assert_eq!(Problem.solve_second(&input), Ok(Count::Small(21)));
+
+        let params = Params::new(vec![("unfold".to_owned(), "0".to_owned())]);
+        assert!(Problem
+            .read_input_with(EXAMPLE.as_bytes(), &params)
+            .is_err());
+    }
+
+    #[test]
+    fn switches_to_big_counts_on_overflow() {
+        // n groups of 1 in 4n - 1 springs fit in C(3n, n) ways
+        let report: Report = "??? 1".parse().unwrap();
+        assert_eq!(report.unfold(3).count(), Count::Small(84));
+
+        let unfolded = report.unfold(30);
+        let product =
+            |range: std::ops::RangeInclusive<u32>| range.map(BigUint::from).product::<BigUint>();
+        assert_eq!(unfolded.count_posibilities::<usize>(), None);
+        assert_eq!(
+            unfolded.count(),
+            Count::Big(product(61..=90) / product(1..=30))
+        );
+    }
+}
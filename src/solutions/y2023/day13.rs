@@ -0,0 +1,328 @@
+use crate::solutions::parse::{read_lines, ParseError};
+use crate::solutions::Solver;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::iter::zip;
+
+pub struct Problem;
+
+/// Rows and columns as bitmasks, with a set bit for each rock (`#`). Lines
+/// longer than 64 cells span several words.
+pub struct Pattern {
+    /// 1-based line of the input where the pattern starts
+    line: usize,
+    rows: Vec<Vec<u64>>,
+    columns: Vec<Vec<u64>>,
+}
+
+/// Words needed for a line of `len` cells.
+fn words(len: usize) -> usize {
+    len.div_ceil(u64::BITS as usize)
+}
+
+/// Bit `i` of a multi-word bitmask.
+fn get_bit(line: &[u64], i: usize) -> bool {
+    line[i / u64::BITS as usize] >> (i % u64::BITS as usize) & 1 == 1
+}
+
+/// Sets bit `i` of a multi-word bitmask.
+fn set_bit(line: &mut [u64], i: usize) {
+    line[i / u64::BITS as usize] |= 1 << (i % u64::BITS as usize);
+}
+
+/// Fold line, after the 0-based row or column.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Symmetry {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Display for Symmetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Symmetry::Vertical(v) => write!(f, "vertical after column {}", v + 1),
+            Symmetry::Horizontal(v) => write!(f, "horizontal after row {}", v + 1),
+        }
+    }
+}
+
+/// A fold line, with the number of cells that don't match their mirror image.
+#[derive(Debug, PartialEq)]
+pub struct Reflection {
+    pub symmetry: Symmetry,
+    pub mismatches: usize,
+}
+
+impl Display for Reflection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.mismatches {
+            1 => write!(f, "{} (1 mismatch)", self.symmetry),
+            n => write!(f, "{} ({n} mismatches)", self.symmetry),
+        }
+    }
+}
+
+/// Comma separated, for diagnostics.
+fn list(items: &[impl Display]) -> String {
+    items
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Pattern {
+    fn new(line: usize, rows: Vec<Vec<u64>>, width: usize) -> Self {
+        let mut columns = vec![vec![0; words(rows.len())]; width];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, column) in columns.iter_mut().enumerate() {
+                if get_bit(row, x) {
+                    set_bit(column, y);
+                }
+            }
+        }
+        Pattern {
+            line,
+            rows,
+            columns,
+        }
+    }
+
+    /// Every fold line with at most `max_mismatches` mismatched cells,
+    /// horizontal ones first, to analyse ambiguous patterns.
+    pub fn get_reflections(&self, max_mismatches: usize) -> Vec<Reflection> {
+        let horizontal = get_reflections(&self.rows, max_mismatches)
+            .map(|(v, mismatches)| (Symmetry::Horizontal(v), mismatches));
+        let vertical = get_reflections(&self.columns, max_mismatches)
+            .map(|(v, mismatches)| (Symmetry::Vertical(v), mismatches));
+
+        horizontal
+            .chain(vertical)
+            .map(|(symmetry, mismatches)| Reflection {
+                symmetry,
+                mismatches,
+            })
+            .collect()
+    }
+
+    /// The reflection with exactly `smudges` cells that don't match their
+    /// mirror. Patterns with several use the first one, horizontal ones first,
+    /// and print the others as a warning.
+    pub fn get_symmetry(&self, smudges: usize) -> Result<Symmetry, String> {
+        // Near misses, reported when there is no reflection
+        let (candidates, closest): (Vec<_>, Vec<_>) = self
+            .get_reflections(smudges + 1)
+            .into_iter()
+            .partition(|v| v.mismatches == smudges);
+
+        match &candidates[..] {
+            [] if closest.is_empty() => Err(format!(
+                "Pattern at line {} has no reflection with {smudges} smudges",
+                self.line
+            )),
+            [] => Err(format!(
+                "Pattern at line {} has no reflection with {smudges} smudges, closest: {}",
+                self.line,
+                list(&closest)
+            )),
+            [first, ..] => {
+                if candidates.len() > 1 {
+                    eprintln!(
+                        "Pattern at line {} has {} reflections with {smudges} smudges: {}, using the first",
+                        self.line,
+                        candidates.len(),
+                        list(&candidates.iter().map(|v| v.symmetry).collect::<Vec<_>>())
+                    );
+                }
+                Ok(first.symmetry)
+            }
+        }
+    }
+}
+
+/// Every line `c` such that folding after it leaves at most `max_mismatches`
+/// mismatched cells between the lines and their mirror images, with that count.
+fn get_reflections(
+    lines: &[Vec<u64>],
+    max_mismatches: usize,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    (0..lines.len().saturating_sub(1)).filter_map(move |c| {
+        let mut mismatches = 0;
+        for (a, b) in zip(lines[..=c].iter().rev(), &lines[(c + 1)..]) {
+            mismatches += zip(a, b)
+                .map(|(a, b)| (a ^ b).count_ones() as usize)
+                .sum::<usize>();
+            if mismatches > max_mismatches {
+                return None;
+            }
+        }
+        Some((c, mismatches))
+    })
+}
+
+fn summarize(patterns: &[Pattern], smudges: usize) -> Result<usize, String> {
+    patterns
+        .iter()
+        .map(|v| {
+            Ok(match v.get_symmetry(smudges)? {
+                Symmetry::Vertical(v) => v + 1,
+                Symmetry::Horizontal(v) => 100 * (v + 1),
+            })
+        })
+        .sum()
+}
+
+impl Solver for Problem {
+    type Input = Vec<Pattern>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(reader)?;
+
+        let mut result = Vec::new();
+        let mut rows = Vec::new();
+        let mut width = 0;
+        let mut start = 0;
+
+        for (i, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                if !rows.is_empty() {
+                    result.push(Pattern::new(start + 1, std::mem::take(&mut rows), width));
+                }
+            } else if let Some(x) = line.find(|c| c != '#' && c != '.') {
+                return Err(ParseError::at_column(x, "Unknown terrain").on_line(i, line));
+            } else if !rows.is_empty() && line.len() != width {
+                return Err(ParseError::new("Pattern rows have different lengths").on_line(i, line));
+            } else {
+                if rows.is_empty() {
+                    start = i;
+                }
+                width = line.len();
+                let mut row = vec![0; words(width)];
+                for (x, _) in line.bytes().enumerate().filter(|(_, c)| *c == b'#') {
+                    set_bit(&mut row, x);
+                }
+                rows.push(row);
+            }
+        }
+        if !rows.is_empty() {
+            result.push(Pattern::new(start + 1, rows, width));
+        }
+
+        Ok(result)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+        summarize(input, 0)
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+        summarize(input, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    examples! {
+        example: EXAMPLE => (405, 400),
+    }
+
+    #[test]
+    fn reports_every_reflection() {
+        let patterns = Problem.read_input(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(patterns[0].line, 1);
+        assert_eq!(patterns[1].line, 9);
+        assert_eq!(
+            patterns[0].get_reflections(1),
+            [
+                Reflection {
+                    symmetry: Symmetry::Horizontal(2),
+                    mismatches: 1
+                },
+                Reflection {
+                    symmetry: Symmetry::Vertical(4),
+                    mismatches: 0
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_patterns_larger_than_a_word() {
+        // 70 by 70, mirrored after row 35, with a smudge past the first word
+        let size = 70;
+        let mut cells = (0..size)
+            .map(|y: usize| {
+                let y = y.min(size - 1 - y);
+                (0..size)
+                    .map(|x| {
+                        if ((x * 73856093) ^ (y * 19349663)) % 7 < 3 {
+                            b'#'
+                        } else {
+                            b'.'
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        cells[66][65] ^= b'#' ^ b'.';
+        let input = cells
+            .iter()
+            .map(|row| String::from_utf8_lossy(row) + "\n")
+            .collect::<String>();
+
+        let patterns = Problem.read_input(input.as_bytes()).unwrap();
+        assert_eq!(patterns[0].rows.len(), size);
+        assert_eq!(patterns[0].columns.len(), size);
+        assert_eq!(Problem.solve_first(&patterns).ok(), None);
+        assert_eq!(Problem.solve_second(&patterns), Ok(3500));
+    }
+
+    #[test]
+    fn rejects_patterns_without_reflection() {
+        let patterns = Problem.read_input("#.\n##\n".as_bytes()).unwrap();
+        assert_eq!(
+            Problem.solve_first(&patterns),
+            Err(
+                "Pattern at line 1 has no reflection with 0 smudges, closest: \
+                 horizontal after row 1 (1 mismatch), vertical after column 1 (1 mismatch)"
+                    .to_owned()
+            )
+        );
+
+        let patterns = Problem.read_input("#..\n.#.\n..#\n".as_bytes()).unwrap();
+        assert_eq!(
+            Problem.solve_first(&patterns),
+            Err("Pattern at line 1 has no reflection with 0 smudges".to_owned())
+        );
+    }
+
+    #[test]
+    fn uses_the_first_of_several_reflections() {
+        let patterns = Problem.read_input("..\n..\n".as_bytes()).unwrap();
+        assert_eq!(patterns[0].get_symmetry(0), Ok(Symmetry::Horizontal(0)));
+        assert_eq!(Problem.solve_first(&patterns), Ok(100));
+    }
+}